@@ -1,8 +1,9 @@
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::env;
 use std::error::Error;
 use std::fs;
+use std::iter;
 use std::path::{Path, PathBuf};
 
 #[derive(Deserialize)]
@@ -11,6 +12,17 @@ struct NickData {
     names_by_irregular_nick: HashMap<String, Vec<String>>,
 }
 
+#[derive(Deserialize)]
+struct LanguageNickData {
+    names_by_nick: HashMap<String, Vec<String>>,
+}
+
+#[derive(Deserialize)]
+struct VariantData {
+    spelling_variants: Vec<Vec<String>>,
+    equivalent_names: Vec<Vec<String>>,
+}
+
 #[derive(Deserialize)]
 struct TitleData {
     honorific_prefixes: HashMap<String, String>,
@@ -22,6 +34,18 @@ struct NameData {
     two_letter_given_names: Vec<String>,
     uncapitalized_particles: Vec<String>,
     additional_surname_prefixes: Vec<String>,
+    romanized_surnames: Vec<Vec<String>>,
+    locales: HashMap<String, LocaleData>,
+}
+
+#[derive(Deserialize)]
+struct LocaleData {
+    surname_prefixes: Vec<String>,
+    conjunctions: Vec<String>,
+    #[serde(default)]
+    given_names: Vec<String>,
+    #[serde(default)]
+    family_names: Vec<String>,
 }
 
 #[derive(Deserialize)]
@@ -48,6 +72,75 @@ fn main() -> Result<()> {
         |vs| format!("&[{}] as &[_]", quoted_comma_separated(vs)),
     )?;
 
+    // Nicknames particular to one language, each behind a cargo feature
+    for language in &["de", "es", "it", "ru"] {
+        let feature = format!("CARGO_FEATURE_NICKNAMES_{}", language.to_uppercase());
+        if env::var_os(feature).is_none() {
+            continue;
+        }
+
+        let json = read_file(&input, &format!("build/nicknames/{}.json", language))?;
+        let language_nicks: LanguageNickData = serde_json::from_str(&json)?;
+        write_map(
+            &output.join(format!("names_by_nick_{}.rs", language)),
+            &language_nicks.names_by_nick,
+            |vs| format!("&[{}] as &[_]", quoted_comma_separated(vs)),
+        )?;
+    }
+
+    let json = read_file(&input, "build/name_data.json")?;
+    let names: NameData = serde_json::from_str(&json)?;
+
+    // Map each spelling of a given name (as lowercase letters only, e.g.
+    // "abdalrahman") to the first one listed for it
+    let json = read_file(&input, "build/variant_data.json")?;
+    let variant_data: VariantData = serde_json::from_str(&json)?;
+    let spelling_variants = variant_data
+        .spelling_variants
+        .iter()
+        .flat_map(|spellings| spellings.iter().map(move |s| (s, &spellings[0])))
+        .map(|(spelling, first)| (folded(spelling), folded(first)))
+        .collect::<HashMap<_, _>>();
+    write_map(
+        &output.join("spelling_variants.rs"),
+        &spelling_variants,
+        |v| format!("\"{}\"", v),
+    )?;
+
+    // Map each language's form of a given name (folded the same way) to the
+    // first form listed for each name it may be a form of (e.g. "Jacques"
+    // for both "James" and "Jacob")
+    let mut equivalent_names = HashMap::<_, Vec<String>>::new();
+    for forms in &variant_data.equivalent_names {
+        for form in forms {
+            equivalent_names
+                .entry(folded(form))
+                .or_default()
+                .push(folded(&forms[0]));
+        }
+    }
+    write_map(
+        &output.join("equivalent_names.rs"),
+        &equivalent_names,
+        |vs| format!("&[{}] as &[_]", quoted_comma_separated(vs)),
+    )?;
+
+    // Every name the nickname data knows about, whether nickname or root,
+    // plus common given names from particular locales
+    let given_names = nicks
+        .names_by_irregular_nick
+        .iter()
+        .flat_map(|(nick, names)| iter::once(nick).chain(names))
+        .chain(nicks.names_by_nick_prefix.values().flatten())
+        .chain(names.locales.values().flat_map(|l| &l.given_names))
+        .chain(variant_data.spelling_variants.iter().flatten())
+        .cloned()
+        .collect::<BTreeSet<_>>();
+    write_set(
+        &output.join("given_names.rs"),
+        &given_names.into_iter().collect::<Vec<_>>(),
+    )?;
+
     let json = read_file(&input, "build/title_data.json")?;
     let titles: TitleData = serde_json::from_str(&json)?;
     write_map(
@@ -61,8 +154,6 @@ fn main() -> Result<()> {
         |v| format!("\"{}\"", v),
     )?;
 
-    let json = read_file(&input, "build/name_data.json")?;
-    let names: NameData = serde_json::from_str(&json)?;
     let two_letter_given_names = names
         .two_letter_given_names
         .iter()
@@ -81,8 +172,12 @@ fn main() -> Result<()> {
         .map(|n| format!("{}{}", n[..1].to_uppercase(), &n[1..]))
         .collect::<Vec<String>>();
     let mut particles_and_conjunctions = capitalized_uncapitalized_particles.clone();
-    particles_and_conjunctions.push("E".to_string());
-    particles_and_conjunctions.push("Y".to_string());
+    for conjunction in names.locales.values().flat_map(|data| &data.conjunctions) {
+        let capitalized = format!("{}{}", conjunction[..1].to_uppercase(), &conjunction[1..]);
+        if !particles_and_conjunctions.contains(&capitalized) {
+            particles_and_conjunctions.push(capitalized);
+        }
+    }
     write_set(
         &output.join("particles_and_conjunctions.rs"),
         &particles_and_conjunctions,
@@ -91,6 +186,55 @@ fn main() -> Result<()> {
     surname_prefixes.extend_from_slice(capitalized_uncapitalized_particles.as_slice());
     surname_prefixes.extend_from_slice(names.additional_surname_prefixes.as_slice());
     write_set(&output.join("surname_prefixes.rs"), &surname_prefixes)?;
+    for (locale, data) in &names.locales {
+        // Store lowercase, capitalized, and uppercase versions, because we
+        // check against both namecased and raw words
+        let particles = data
+            .surname_prefixes
+            .iter()
+            .map(|p| (p, "Particle::SurnamePrefix"))
+            .chain(
+                data.conjunctions
+                    .iter()
+                    .map(|c| (c, "Particle::Conjunction")),
+            )
+            .flat_map(|(p, kind)| {
+                let capitalized = format!("{}{}", p[..1].to_uppercase(), &p[1..]);
+                vec![
+                    (p.clone(), kind),
+                    (capitalized, kind),
+                    (p.to_uppercase(), kind),
+                ]
+            })
+            .collect::<HashMap<_, _>>();
+        write_map(
+            &output.join(format!("particles_{}.rs", locale)),
+            &particles,
+            |v| v.to_string(),
+        )?;
+        write_set(
+            &output.join(format!("family_names_{}.rs", locale)),
+            &data.family_names,
+        )?;
+    }
+
+    // Map each romanization of a surname to the first one listed for it
+    let mut romanized_surnames = HashMap::new();
+    for variants in &names.romanized_surnames {
+        for variant in variants {
+            if romanized_surnames
+                .insert(variant.as_str(), &variants[0])
+                .is_some()
+            {
+                return Err(format!("Duplicate romanized surname: {}", variant).into());
+            }
+        }
+    }
+    write_map(
+        &output.join("romanized_surnames.rs"),
+        &romanized_surnames,
+        |v| format!("\"{}\"", v),
+    )?;
 
     let json = read_file(&input, "build/generation_data.json")?;
     let gens: GenerationData = serde_json::from_str(&json)?;
@@ -136,6 +280,13 @@ fn read_file(input_dir: &Path, file_path: &str) -> Result<String> {
     Ok(s)
 }
 
+fn folded(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
 fn quoted_comma_separated(vs: &[String]) -> String {
     vs.iter()
         .map(|v| format!("\"{}\"", v))
@@ -0,0 +1,154 @@
+//! The structure of Arabic names, like "Abu Bakr Muhammad ibn Zakariya
+//! al-Razi": an optional kunya ("Abu Bakr", i.e. father of Bakr), the ism or
+//! given name ("Muhammad"), a nasab or chain of patronymics ("ibn
+//! Zakariya"), and a nisba or family name ("al-Razi").
+
+use super::namepart::NamePart;
+use super::patronymic;
+use super::Locale;
+
+// Introduce a kunya (e.g. "Abu Bakr", "Umm Kulthum")
+const KUNYA_MARKERS: [&str; 4] = ["abu", "abou", "umm", "um"];
+
+// Begin a compound ism (e.g. "Abd al-Rahman")
+const ABD_FORMS: [&str; 4] = ["abd", "abdel", "abdul", "abdal"];
+
+// The article "al", with or without assimilation (e.g. "ad-Din", "as-Sayyid")
+const ARTICLE_FORMS: [&str; 11] = [
+    "al-", "el-", "ad-", "ar-", "as-", "at-", "az-", "an-", "ash-", "ath-", "adh-",
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Structure {
+    /// How many leading words make up the kunya
+    pub kunya_len: usize,
+
+    /// Where the nisba or family name starts, counting from after the kunya
+    pub surname_index: usize,
+}
+
+/// Finds the kunya and family name, if the words look like an Arabic name
+/// with at least a given name and one more name
+pub fn find_structure(words: &[NamePart]) -> Option<Structure> {
+    let kunya_len = if words.len() > 3 && is_kunya_marker(&words[0]) && words[1].is_namelike() {
+        2
+    } else {
+        0
+    };
+
+    let words = &words[kunya_len..];
+    if words.len() < 2 || !words.iter().all(|w| w.is_namelike()) {
+        return None;
+    }
+
+    // The ism, which may be compound (e.g. "Abd al-Rahman")
+    let ism_len = if words.len() > 2 && is_abd(words[0].word) {
+        2
+    } else {
+        1
+    };
+
+    // The nasab (e.g. "ibn Musa ibn Shakir")
+    let mut nasab_end = ism_len;
+    while nasab_end + 1 < words.len() && patronymic::is_particle(words[nasab_end].word, Locale::Ar)
+    {
+        nasab_end += 2;
+    }
+
+    let surname_index = if nasab_end == words.len() {
+        // No nisba or family name, so we take the last link in the chain, as
+        // in "Ibn Khaldun"
+        if nasab_end > ism_len {
+            nasab_end - 2
+        } else {
+            return None;
+        }
+    } else {
+        // Any nisbas or family name start with the article (e.g. "al-Razi",
+        // "Al Saud"); otherwise the last word is the family name
+        (nasab_end..words.len())
+            .find(|&i| has_article(words, i))
+            .unwrap_or(words.len() - 1)
+    };
+
+    Some(Structure {
+        kunya_len,
+        surname_index,
+    })
+}
+
+fn is_kunya_marker(word: &NamePart) -> bool {
+    KUNYA_MARKERS
+        .iter()
+        .any(|m| m.eq_ignore_ascii_case(word.word))
+}
+
+// Whether the word begins a compound given name, like "Abd" in "Abd
+// al-Rahman"
+pub fn is_abd(word: &str) -> bool {
+    ABD_FORMS.iter().any(|m| m.eq_ignore_ascii_case(word))
+}
+
+fn has_article(words: &[NamePart], i: usize) -> bool {
+    let word = words[i].word;
+    let attached = ARTICLE_FORMS.iter().any(|article| {
+        word.len() > article.len()
+            && word.is_char_boundary(article.len())
+            && word[..article.len()].eq_ignore_ascii_case(article)
+    });
+    let separate =
+        i + 1 < words.len() && (word.eq_ignore_ascii_case("al") || word.eq_ignore_ascii_case("el"));
+    attached || separate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::namepart::Location;
+    use super::*;
+
+    fn structure(input: &str) -> Option<(usize, usize)> {
+        let words: Vec<_> = NamePart::all_from_text(input, true, Location::Start).collect();
+        find_structure(&words).map(|s| (s.kunya_len, s.surname_index))
+    }
+
+    #[test]
+    fn kunya_nasab_nisba() {
+        assert_eq!(
+            Some((2, 3)),
+            structure("Abu Bakr Muhammad ibn Zakariya al-Razi")
+        );
+        assert_eq!(Some((0, 3)), structure("Muhammad ibn Musa al-Khwarizmi"));
+    }
+
+    #[test]
+    fn family_name() {
+        assert_eq!(Some((0, 3)), structure("Mohammed bin Salman Al Saud"));
+        assert_eq!(Some((0, 2)), structure("Mohammed Ahmed Hassan"));
+        assert_eq!(Some((0, 2)), structure("Ali Hasan al-Basri al-Baghdadi"));
+    }
+
+    #[test]
+    fn nasab_only() {
+        assert_eq!(Some((0, 3)), structure("Muhammad ibn Musa ibn Shakir"));
+        assert_eq!(Some((0, 1)), structure("Ahmad ibn Hanbal"));
+    }
+
+    #[test]
+    fn compound_ism() {
+        assert_eq!(Some((0, 2)), structure("Abd al-Rahman Kawakibi"));
+        assert_eq!(Some((0, 2)), structure("Abd al-Rahman al-Kawakibi"));
+    }
+
+    #[test]
+    fn kunya_alone() {
+        // Not enough left to be the ism and another name
+        assert_eq!(Some((0, 2)), structure("Abu Bakr al-Siddiq"));
+        assert_eq!(Some((0, 1)), structure("Umm Kulthum"));
+    }
+
+    #[test]
+    fn unstructured() {
+        assert_eq!(None, structure("Muhammad"));
+        assert_eq!(None, structure("M. al-Razi"));
+    }
+}
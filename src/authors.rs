@@ -0,0 +1,304 @@
+use super::multiple::CONJUNCTIONS;
+use super::{Name, ParseError, ParseOptions};
+use std::ops::Range;
+
+const ET_AL: [&str; 5] = ["et al.", "et al", "et. al.", "and others", "& others"];
+
+#[derive(Debug)]
+struct Piece {
+    span: Range<usize>,
+    // Whether a semicolon or conjunction separates this piece from the next,
+    // so that they can't be two halves of one "Surname, Given" author
+    hard_break: bool,
+}
+
+/// Parses a list of authors, as found in bibliographic data, into a `Name`
+/// and the byte range of the input it came from, for each author.
+///
+/// Authors may be separated by semicolons, commas, "and", or "&", and the
+/// list may end with "et al.". Where commas separate both authors and
+/// surnames from given names, as in "Doe, J., Smith, K.", a surname is paired
+/// with the following initials or given name; otherwise, as in "Jane Doe,
+/// John Smith", each comma-separated part is taken to be a separate author.
+/// Initials may also follow the surname without a comma, as in "Doe J,
+/// Smith K" (see `ParseOptions::initials_after_surname`). Authors which can't
+/// be parsed are skipped.
+///
+/// # Examples
+/// ```
+/// use human_name::parse_author_list;
+///
+/// let input = "Doe, J., Smith, K. & Jones, L. et al.";
+/// let authors = parse_author_list(input);
+/// assert_eq!(3, authors.len());
+/// assert_eq!("J. Doe", authors[0].0.display_full());
+/// assert_eq!("Doe, J.", &input[authors[0].1.clone()]);
+/// assert_eq!("L. Jones", authors[2].0.display_full());
+///
+/// let authors = parse_author_list("Jane Doe, John Smith, and Al Jones");
+/// assert_eq!(3, authors.len());
+/// assert_eq!("Al Jones", authors[2].0.display_full());
+///
+/// let authors = parse_author_list("Doe, John");
+/// assert_eq!(1, authors.len());
+/// assert_eq!("John Doe", authors[0].0.display_full());
+/// ```
+pub fn parse_author_list(input: &str) -> Vec<(Name, Range<usize>)> {
+    let pieces = split_pieces(input);
+    let options = ParseOptions::new().initials_after_surname(true);
+
+    let mut authors: Vec<(Name, Range<usize>)> = Vec::with_capacity(pieces.len());
+    let mut i = 0;
+    while i < pieces.len() {
+        let piece = &pieces[i];
+        let text = &input[piece.span.clone()];
+
+        // "Doe, J." or "van der Berg, H.": pair a surname with following
+        // initials or given name
+        if !piece.hard_break
+            && i + 1 < pieces.len()
+            && (is_single_word(text) || looks_like_given_name(&input[pieces[i + 1].span.clone()]))
+        {
+            let span = piece.span.start..pieces[i + 1].span.end;
+            if let Ok(name) = Name::try_parse_with(&input[span.clone()], &options) {
+                authors.push((name, span));
+                i += 2;
+                continue;
+            }
+        }
+
+        match Name::try_parse_with(text, &options) {
+            Ok(name) => authors.push((name, piece.span.clone())),
+            // "Doe, John, Jr.": attach a trailing suffix to the prior author
+            Err(ParseError::OnlyTitles { .. }) if i > 0 && !pieces[i - 1].hard_break => {
+                if let Some((prior, prior_span)) = authors.last_mut() {
+                    let span = prior_span.start..piece.span.end;
+                    if let Ok(name) = Name::try_parse_with(&input[span.clone()], &options) {
+                        *prior = name;
+                        *prior_span = span;
+                    }
+                }
+            }
+            Err(_) => {}
+        }
+
+        i += 1;
+    }
+
+    authors
+}
+
+fn split_pieces(input: &str) -> Vec<Piece> {
+    let mut pieces = Vec::new();
+
+    let end = et_al_start(input).unwrap_or(input.len());
+
+    let mut start = 0;
+    for (i, c) in input[..end].char_indices() {
+        if c == ',' || c == ';' {
+            push_pieces(input, start..i, c == ';', &mut pieces);
+            start = i + 1;
+        }
+    }
+    push_pieces(input, start..end, true, &mut pieces);
+
+    pieces
+}
+
+// Splits at conjunctions, and trims whitespace, before pushing
+fn push_pieces(input: &str, span: Range<usize>, hard_break: bool, pieces: &mut Vec<Piece>) {
+    let mut start = span.start;
+    let mut offset = span.start;
+    for word in input[span.clone()].split(' ') {
+        let word_start = offset;
+        offset += word.len() + 1;
+
+        if CONJUNCTIONS.iter().any(|c| c.eq_ignore_ascii_case(word)) {
+            push_trimmed(input, start..word_start, true, pieces);
+            start = offset.min(span.end);
+        }
+    }
+    push_trimmed(input, start..span.end, hard_break, pieces);
+}
+
+fn push_trimmed(input: &str, span: Range<usize>, hard_break: bool, pieces: &mut Vec<Piece>) {
+    let text = &input[span.clone()];
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        // E.g. the "and" in "Doe, J., and Smith, K."
+        if hard_break {
+            if let Some(prior) = pieces.last_mut() {
+                prior.hard_break = true;
+            }
+        }
+        return;
+    }
+
+    let start = span.start + (text.len() - text.trim_start().len());
+    pieces.push(Piece {
+        span: start..start + trimmed.len(),
+        hard_break,
+    });
+}
+
+// Finds where a trailing "et al." (or similar) starts, if present
+fn et_al_start(input: &str) -> Option<usize> {
+    let trimmed = input.trim_end();
+    ET_AL.iter().find_map(|suffix| {
+        let start = trimmed.len().checked_sub(suffix.len())?;
+        let preceded_by_separator = trimmed
+            .get(..start)?
+            .chars()
+            .next_back()
+            .map(|c| c.is_whitespace() || c == ',' || c == ';')
+            .unwrap_or(true);
+
+        if trimmed.get(start..)?.eq_ignore_ascii_case(suffix) && preceded_by_separator {
+            Some(start)
+        } else {
+            None
+        }
+    })
+}
+
+fn is_single_word(text: &str) -> bool {
+    !text.contains(' ') && !text.contains('.')
+}
+
+// E.g. "J.", "J. K.", "J.-P." or "John", but not "John Smith"
+fn looks_like_given_name(text: &str) -> bool {
+    is_single_word(text) || text.split_whitespace().all(is_initials)
+}
+
+fn is_initials(word: &str) -> bool {
+    word.split(|c| c == '.' || c == '-')
+        .filter(|part| !part.is_empty())
+        .all(|part| {
+            let mut chars = part.chars();
+            chars.next().map_or(false, char::is_alphabetic) && chars.next().is_none()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn authors(input: &str) -> Vec<(String, &str)> {
+        parse_author_list(input)
+            .into_iter()
+            .map(|(name, span)| (name.display_full().into_owned(), &input[span]))
+            .collect()
+    }
+
+    #[test]
+    fn surname_comma_initials() {
+        assert_eq!(
+            vec![
+                ("J. Doe".to_string(), "Doe, J."),
+                ("K. Smith".to_string(), "Smith, K.")
+            ],
+            authors("Doe, J., Smith, K.")
+        );
+        assert_eq!(
+            vec![
+                ("J. Doe".to_string(), "Doe, J."),
+                ("K. Smith".to_string(), "Smith, K.")
+            ],
+            authors("Doe, J.; Smith, K.")
+        );
+        assert_eq!(
+            vec![
+                ("John Doe, Jr.".to_string(), "Doe, John, Jr."),
+                ("Kate Smith".to_string(), "Smith, Kate")
+            ],
+            authors("Doe, John, Jr., and Smith, Kate")
+        );
+    }
+
+    #[test]
+    fn multi_word_surname_comma_initials() {
+        assert_eq!(
+            vec![
+                ("H. van der Berg".to_string(), "van der Berg, H."),
+                ("J. Doe".to_string(), "Doe, J.")
+            ],
+            authors("van der Berg, H., Doe, J.")
+        );
+        assert_eq!(
+            vec![
+                ("G. Garcia Marquez".to_string(), "Garcia Marquez, G."),
+                ("J. Doe".to_string(), "Doe, J.")
+            ],
+            authors("Garcia Marquez, G., Doe, J.")
+        );
+    }
+
+    #[test]
+    fn surname_initials() {
+        assert_eq!(
+            vec![
+                ("J. Doe".to_string(), "Doe J."),
+                ("K. Smith".to_string(), "Smith K.")
+            ],
+            authors("Doe J., Smith K.")
+        );
+        assert_eq!(
+            vec![
+                ("J. A. Doe".to_string(), "Doe JA"),
+                ("K. Smith".to_string(), "Smith K")
+            ],
+            authors("Doe JA, Smith K")
+        );
+    }
+
+    #[test]
+    fn given_first() {
+        assert_eq!(
+            vec![
+                ("Jane Doe".to_string(), "Jane Doe"),
+                ("John Smith".to_string(), "John Smith"),
+                ("Al Jones".to_string(), "Al Jones")
+            ],
+            authors("Jane Doe, John Smith & Al Jones")
+        );
+    }
+
+    #[test]
+    fn et_al() {
+        assert_eq!(
+            vec![("Jane Doe".to_string(), "Jane Doe")],
+            authors("Jane Doe et al.")
+        );
+        assert_eq!(
+            vec![("Jane Doe".to_string(), "Jane Doe")],
+            authors("Jane Doe, et al")
+        );
+        assert_eq!(
+            vec![("J. Doe".to_string(), "Doe, J.")],
+            authors("Doe, J., and others")
+        );
+    }
+
+    #[test]
+    fn non_ascii() {
+        assert_eq!(1, authors("Jürgen Müller").len());
+        assert_eq!(
+            vec![("J. Doe".to_string(), "Doe, J.")],
+            authors("Doe, J., Müller")
+        );
+        let input = "Müller, J., et al.";
+        let authors = parse_author_list(input);
+        assert_eq!(1, authors.len());
+        assert_eq!("Müller, J.", &input[authors[0].1.clone()]);
+    }
+
+    #[test]
+    fn unparseable() {
+        assert!(authors("").is_empty());
+        assert!(authors("et al.").is_empty());
+        assert_eq!(
+            vec![("Jane Doe".to_string(), "Jane Doe")],
+            authors("Jane Doe; 12345")
+        );
+    }
+}
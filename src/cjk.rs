@@ -0,0 +1,158 @@
+//! Names written in Chinese, Japanese, or Korean script, which put the
+//! family name first, and are often written without a space (e.g. "毛泽东",
+//! "山田太郎", or "김정은").
+
+use super::features::categorize_chars;
+use super::namepart::{Category, NamePart};
+use super::options::Locale;
+use std::borrow::Cow;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+// Common family names, including compound names (e.g. "欧阳" or "남궁")
+static FAMILY_NAMES_ZH: phf::Set<&'static str> =
+    include!(concat!(env!("OUT_DIR"), "/family_names_zh.rs"));
+static FAMILY_NAMES_JA: phf::Set<&'static str> =
+    include!(concat!(env!("OUT_DIR"), "/family_names_ja.rs"));
+static FAMILY_NAMES_KO: phf::Set<&'static str> =
+    include!(concat!(env!("OUT_DIR"), "/family_names_ko.rs"));
+
+// Longest family name we look for, in characters (e.g. "長谷川")
+const MAX_FAMILY_NAME_LEN: usize = 3;
+
+pub fn is_cjk_locale(locale: Locale) -> bool {
+    matches!(locale, Locale::Zh | Locale::Ja | Locale::Ko)
+}
+
+/// Splits a name written entirely in CJK script into the family name and
+/// given name, at the space if there is one, or else after the longest
+/// known family name which leaves a given name
+pub fn split_name(text: &str, locale: Locale) -> Option<(&str, &str)> {
+    let family_names = match locale {
+        Locale::Zh => &FAMILY_NAMES_ZH,
+        Locale::Ja => &FAMILY_NAMES_JA,
+        Locale::Ko => &FAMILY_NAMES_KO,
+        _ => return None,
+    };
+
+    let text = text.trim();
+    if !text.chars().all(|c| c == ' ' || is_cjk(c)) {
+        return None;
+    }
+
+    let is_family_name =
+        |word: &str| family_names.contains(word.nfc().collect::<String>().as_str());
+
+    let mut words = text.split(' ').filter(|w| !w.is_empty());
+    match (words.next(), words.next(), words.next()) {
+        (Some(first), Some(second), None) => {
+            // Given name first, as when adapted to Western order (e.g. "太郎
+            // 山田")
+            if is_family_name(second) && !is_family_name(first) {
+                Some((second, first))
+            } else {
+                Some((first, second))
+            }
+        }
+        (Some(word), None, None) => {
+            // Characters, counting decomposed Hangul syllables and kana with
+            // voicing marks as one
+            let boundaries: Vec<usize> = word
+                .grapheme_indices(true)
+                .map(|(i, _)| i)
+                .skip(1)
+                .take(MAX_FAMILY_NAME_LEN)
+                .collect();
+
+            let len = boundaries
+                .iter()
+                .rev()
+                .find(|&&i| is_family_name(&word[..i]))
+                .copied()
+                .or_else(|| {
+                    // Chinese and Korean family names are almost all one
+                    // character, but Japanese ones vary too much to guess
+                    if locale == Locale::Ja {
+                        None
+                    } else {
+                        boundaries.first().copied()
+                    }
+                })?;
+            Some((&word[..len], &word[len..]))
+        }
+        _ => None,
+    }
+}
+
+/// A family or given name in CJK script, as a single word (which we
+/// wouldn't otherwise take a run of characters for)
+pub fn name_part(word: &str) -> NamePart<'_> {
+    NamePart {
+        word,
+        counts: categorize_chars(word),
+        category: Category::Name(Cow::Borrowed(word)),
+    }
+}
+
+/// Whether the word is written in CJK script (at least in part)
+pub fn has_cjk(word: &str) -> bool {
+    word.chars().any(is_cjk)
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{1100}'..='\u{11FF}' // Hangul Jamo
+        | '\u{3005}' // Ideographic iteration mark (e.g. "佐々木")
+        | '\u{3040}'..='\u{30FF}' // Hiragana and Katakana
+        | '\u{3130}'..='\u{318F}' // Hangul Compatibility Jamo
+        | '\u{3400}'..='\u{4DBF}' // CJK Unified Ideographs Extension A
+        | '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+        | '\u{AC00}'..='\u{D7AF}' // Hangul Syllables
+        | '\u{F900}'..='\u{FAFF}' // CJK Compatibility Ideographs
+        | '\u{20000}'..='\u{2FA1F}' // Supplementary ideographs
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chinese() {
+        assert_eq!(Some(("毛", "泽东")), split_name("毛泽东", Locale::Zh));
+        assert_eq!(Some(("欧阳", "修")), split_name("欧阳修", Locale::Zh));
+        assert_eq!(Some(("諸葛", "亮")), split_name("諸葛亮", Locale::Zh));
+        assert_eq!(Some(("鄭", "和")), split_name("鄭和", Locale::Zh));
+        assert_eq!(Some(("毛", "泽东")), split_name("毛 泽东", Locale::Zh));
+    }
+
+    #[test]
+    fn japanese() {
+        assert_eq!(Some(("山田", "太郎")), split_name("山田太郎", Locale::Ja));
+        assert_eq!(Some(("佐々木", "希")), split_name("佐々木希", Locale::Ja));
+        assert_eq!(Some(("山田", "太郎")), split_name("太郎 山田", Locale::Ja));
+        assert_eq!(None, split_name("やまだたろう", Locale::Ja));
+    }
+
+    #[test]
+    fn korean() {
+        let decomposed = |s: &str| s.nfkd().collect::<String>();
+
+        let name = decomposed("김정은");
+        let (family, given) = split_name(&name, Locale::Ko).unwrap();
+        assert_eq!(decomposed("김"), family);
+        assert_eq!(decomposed("정은"), given);
+
+        let name = decomposed("남궁민");
+        let (family, given) = split_name(&name, Locale::Ko).unwrap();
+        assert_eq!(decomposed("남궁"), family);
+        assert_eq!(decomposed("민"), given);
+    }
+
+    #[test]
+    fn not_cjk() {
+        assert_eq!(None, split_name("Mao Zedong", Locale::Zh));
+        assert_eq!(None, split_name("毛泽东", Locale::Any));
+        assert_eq!(None, split_name("毛", Locale::Zh));
+    }
+}
@@ -1,7 +1,14 @@
+use super::arabic;
 use super::case::*;
-use super::nickname::have_matching_variants;
+use super::nickname::{
+    are_equivalent_names, are_spelling_variants, have_matching_variants, is_locale_nickname,
+};
+use super::romanization;
+use super::surname::SurnameOrder;
 use super::transliterate;
-use super::{Location, Name};
+use super::{ComparisonOptions, Locale, Location, Name};
+use compact_str::CompactString;
+use smallvec::SmallVec;
 use std::borrow::Cow;
 use std::convert::TryInto;
 use std::iter;
@@ -49,7 +56,26 @@ impl Name {
     /// In the case of given and middle names, allows one name to be a prefix of
     /// the other, without requiring the prefix end at a word boundary as we do
     /// with surname suffix matches, and supports matching a small number of
-    /// common nicknames and nickname patterns based on the root name.
+    /// common nicknames and nickname patterns based on the root name. A
+    /// nickname captured from the input (see `nickname`) is also accepted in
+    /// place of the given name, so "William 'Chip' Smith" is consistent with
+    /// "Chip Smith". Known spellings of the same name, as with
+    /// transliterations of Arabic names, match too, so "Mohamed Salah" is
+    /// consistent with "Mehmet Salah", and "Abd al-Rahman Kawakibi" with
+    /// "Abdulrahman Kawakibi".
+    ///
+    /// A patronymic among the middle names (see `patronymic`) is optional
+    /// where the other name has none, so "Mohammed bin Salman Al Saud" is
+    /// consistent with "Mohammed Salman Al Saud", but "Ivan Petrovich
+    /// Sidorov" is not consistent with "Ivan Alexeyevich Sidorov".
+    ///
+    /// The first part of a double surname joined by a conjunction is enough,
+    /// so "Juan Velasquez y Garcia" is consistent with "Juan Velasquez".
+    /// Otherwise, as with any surname, the final words are enough, so "Iria
+    /// del Río Gayo" is consistent with "Iria Gayo", whatever the locale. To
+    /// match only the paternal part of a double surname, as in "Gabriel
+    /// García" and "Gabriel García Márquez", see
+    /// `ComparisonOptions::match_double_surname_parts`.
     ///
     /// # Limitations
     ///
@@ -70,11 +96,335 @@ impl Name {
 
         // Check given name(s) first because if we got this far, we know that
         // at least the last characters of the surnames are consistent
-        self.given_and_middle_names_consistent(other)
+        (self.given_and_middle_names_consistent(other)
+            || self.nickname_consistent(other)
+            || self.patronymic_consistent(other)
+            || self.compound_given_name_consistent(other))
             && self.surname_consistent(other)
             && self.suffix_consistent(other)
     }
 
+    /// Like `consistent_with`, but with options controlling the comparison.
+    ///
+    /// ```
+    /// use human_name::{ComparisonOptions, Name};
+    ///
+    /// let options = ComparisonOptions::new().match_former_surnames(true);
+    ///
+    /// let maria = Name::parse("Maria Lopez (Garcia)").unwrap();
+    /// assert!(maria.consistent_with_options(&Name::parse("M. Garcia").unwrap(), &options));
+    /// assert!(maria.consistent_with_options(&Name::parse("M. Lopez").unwrap(), &options));
+    /// assert!(!maria.consistent_with_options(&Name::parse("M. Perez").unwrap(), &options));
+    /// ```
+    pub fn consistent_with_options(&self, other: &Name, options: &ComparisonOptions) -> bool {
+        if self.consistent_with(other) {
+            return true;
+        }
+
+        let surnames_consistent = (self.surname_hash() == other.surname_hash()
+            && self.surname_consistent(other))
+            || (options.match_former_surnames && self.former_surnames_consistent(other))
+            || (options.match_romanized_surnames && self.romanized_surnames_consistent(other))
+            || (options.match_double_surname_parts && self.double_surname_parts_consistent(other));
+
+        surnames_consistent
+            && (self.given_and_middle_names_consistent(other)
+                || self.nickname_consistent(other)
+                || self.patronymic_consistent(other)
+                || self.compound_given_name_consistent(other)
+                || (options.match_equivalent_names && self.equivalent_names_consistent(other))
+                || (!options.nickname_locales.is_empty()
+                    && self.locale_nicknames_consistent(other, &options.nickname_locales)))
+            && self.suffix_consistent(other)
+    }
+
+    // Whether any current or former surname of one name is consistent with
+    // any of the other
+    #[inline(never)]
+    fn former_surnames_consistent(&self, other: &Name) -> bool {
+        if self.former_surnames().next().is_none() && other.former_surnames().next().is_none() {
+            return false;
+        }
+
+        iter::once(self.surname())
+            .chain(self.former_surnames())
+            .any(|mine| {
+                iter::once(other.surname())
+                    .chain(other.former_surnames())
+                    .any(|theirs| Self::surname_strs_consistent(mine, theirs))
+            })
+    }
+
+    // Whether the surnames are single words which romanize the same Chinese or
+    // Korean surname (e.g. "Park" and "Bak")
+    #[inline(never)]
+    fn romanized_surnames_consistent(&self, other: &Name) -> bool {
+        let canonical_surname = |name: &Name| match name.comparison_surname_locations() {
+            [word] => romanization::canonical_surname(&name.text[word.range()]),
+            _ => None,
+        };
+
+        match (canonical_surname(self), canonical_surname(other)) {
+            (Some(mine), Some(theirs)) => mine == theirs,
+            _ => false,
+        }
+    }
+
+    // Fallback for when the given names didn't match: accept a captured
+    // nickname in place of either given name, still comparing middle names
+    // (so "William 'Bill' Adam Smith" is consistent with "Bill A. Smith", but
+    // not "Bill Zachary Smith")
+    #[inline(never)]
+    fn nickname_consistent(&self, other: &Name) -> bool {
+        let my_nicknamed = self.nickname().and_then(|n| self.with_given_name(n));
+        let their_nicknamed = other.nickname().and_then(|n| other.with_given_name(n));
+        if my_nicknamed.is_none() && their_nicknamed.is_none() {
+            return false;
+        }
+
+        let consistent = |mine: Option<&Name>, theirs: Option<&Name>| match (mine, theirs) {
+            (Some(mine), Some(theirs)) => mine.given_and_middle_names_consistent(theirs),
+            _ => false,
+        };
+        let my_nicknamed = my_nicknamed.as_ref();
+        let their_nicknamed = their_nicknamed.as_ref();
+        consistent(my_nicknamed, Some(other))
+            || consistent(Some(self), their_nicknamed)
+            || consistent(my_nicknamed, their_nicknamed)
+    }
+
+    // Fallback for when only one name has a patronymic among its middle
+    // names: compare as if it were missing (e.g. "Mohammed bin Salman Al Saud"
+    // and "Mohammed Salman Al Saud")
+    #[inline(never)]
+    fn patronymic_consistent(&self, other: &Name) -> bool {
+        let (with, without) = match (self.patronymic(), other.patronymic()) {
+            (Some(_), None) => (self, other),
+            (None, Some(_)) => (other, self),
+            _ => return false,
+        };
+
+        match with.without_middle_patronymic() {
+            Some(reduced) => {
+                reduced.given_and_middle_names_consistent(without)
+                    || reduced.nickname_consistent(without)
+            }
+            None => false,
+        }
+    }
+
+    // The same name with any patronymic among the middle names removed
+    fn without_middle_patronymic(&self) -> Option<Name> {
+        let patronymic = self.patronymic()?;
+        let len = patronymic.split(' ').count();
+        let given: SmallVec<[NameWordOrInitial; 4]> = self.given_names_or_initials().collect();
+
+        let is_patronymic = |parts: &[NameWordOrInitial]| {
+            parts
+                .iter()
+                .zip(patronymic.split(' '))
+                .all(|(part, word)| matches!(part, NameWordOrInitial::Word(w, _) if *w == word))
+        };
+        let start = (1..given.len().saturating_sub(len - 1))
+            .find(|&start| is_patronymic(&given[start..start + len]))?;
+
+        let remaining = given[..start].iter().chain(&given[start + len..]).cloned();
+        self.with_given_names(remaining)
+    }
+
+    // Fallback for when a compound given name (e.g. "Abd al-Rahman") may be
+    // written as one word in the other name (e.g. "Abdulrahman"): compare as
+    // if both were written as one word
+    #[inline(never)]
+    fn compound_given_name_consistent(&self, other: &Name) -> bool {
+        let joined_mine = self.with_joined_compound_given_name();
+        let joined_theirs = other.with_joined_compound_given_name();
+        if joined_mine.is_none() && joined_theirs.is_none() {
+            return false;
+        }
+
+        let mine = joined_mine.as_ref().unwrap_or(self);
+        let theirs = joined_theirs.as_ref().unwrap_or(other);
+        mine.given_and_middle_names_consistent(theirs)
+    }
+
+    // The same name with a compound given name joined into one word (e.g.
+    // "Abdalrahman" for "Abd al-Rahman" or "Abd al Rahman")
+    fn with_joined_compound_given_name(&self) -> Option<Name> {
+        let locations = self.given_name_locations();
+        if locations.len() < 2 || !arabic::is_abd(&self.text[locations[0].range()]) {
+            return None;
+        }
+
+        let is_article =
+            |word: &str| word.eq_ignore_ascii_case("al") || word.eq_ignore_ascii_case("el");
+        let joined_len = if locations.len() > 2 && is_article(&self.text[locations[1].range()]) {
+            3
+        } else {
+            2
+        };
+
+        let first = locations[0].range();
+        let last = locations[joined_len - 1].range();
+        let mut joined = String::with_capacity(last.end - first.start);
+        joined.push_str(&self.text[first.clone()]);
+        joined.extend(
+            self.text[first.end..last.end]
+                .chars()
+                .filter(|c| c.is_alphabetic())
+                .flat_map(char::to_lowercase),
+        );
+
+        // The compound name must be the first of the given names, since we
+        // only have locations for words, not initials
+        let mut given = self.given_names_or_initials();
+        match given.next() {
+            Some(NameWordOrInitial::Word(..)) => {}
+            _ => return None,
+        }
+        let rest = given.skip(joined_len - 1);
+        self.with_given_names(iter::once(NameWordOrInitial::Word(&joined, 1)).chain(rest))
+    }
+
+    // Fallback for names recorded in different languages: compare as if we
+    // used the other name's form of each given or middle name which has one
+    // (e.g. "John Sebastian Bach" for "Johann Sebastian Bach", when comparing
+    // with "John S. Bach"). Unlike nicknames, any number of names may differ.
+    #[inline(never)]
+    fn equivalent_names_consistent(&self, other: &Name) -> bool {
+        let is_equivalent = |mine: &str, theirs: &str| {
+            NameWordOrInitial::Word(mine, 1).check_consistency(
+                &NameWordOrInitial::Word(theirs, 1),
+                false,
+                true,
+            ) == ComparisonResult::EquivalentNameMatch
+        };
+        match self.with_given_names_from(other, is_equivalent) {
+            Some(translated) => translated.given_and_middle_names_consistent(other),
+            None => false,
+        }
+    }
+
+    // Fallback for nicknames particular to a language (e.g. "Pepe" for "José"
+    // in Spanish), which we only look up on request, and check the same way
+    #[inline(never)]
+    fn locale_nicknames_consistent(&self, other: &Name, locales: &[Locale]) -> bool {
+        let is_nickname = |mine: &str, theirs: &str| is_locale_nickname(mine, theirs, locales);
+        match self.with_given_names_from(other, is_nickname) {
+            Some(replaced) => replaced.given_and_middle_names_consistent(other),
+            None => false,
+        }
+    }
+
+    // The same name with given and middle names replaced by the other name's
+    // corresponding names, where the two match by some rule other than our
+    // usual comparison (e.g. as forms of the same name)
+    fn with_given_names_from<F>(&self, other: &Name, matches: F) -> Option<Name>
+    where
+        F: Fn(&str, &str) -> bool,
+    {
+        let mut replaced = false;
+        let given: SmallVec<[NameWordOrInitial; 4]> = self
+            .given_names_or_initials()
+            .zip(
+                other
+                    .given_names_or_initials()
+                    .map(Some)
+                    .chain(iter::repeat(None)),
+            )
+            .map(|(mine, theirs)| match (mine, theirs) {
+                (NameWordOrInitial::Word(mine, _), Some(NameWordOrInitial::Word(theirs, len)))
+                    if matches(mine, theirs) =>
+                {
+                    replaced = true;
+                    NameWordOrInitial::Word(theirs, len)
+                }
+                (mine, _) => mine,
+            })
+            .collect();
+
+        if !replaced {
+            return None;
+        }
+        self.with_given_names(given)
+    }
+
+    // The same name with the given name replaced (e.g. by a nickname)
+    fn with_given_name(&self, given_name: &str) -> Option<Name> {
+        let rest = self.given_names_or_initials().skip(1);
+        self.with_given_names(iter::once(NameWordOrInitial::Word(given_name, 1)).chain(rest))
+    }
+
+    // The same name with different given and middle names, but the same
+    // surname, suffix, and everything else we parsed from the input
+    fn with_given_names<'b, I>(&self, given: I) -> Option<Name>
+    where
+        I: IntoIterator<Item = NameWordOrInitial<'b>>,
+    {
+        let mut text = CompactString::with_capacity(self.text.len());
+        let mut initials = String::with_capacity(usize::from(self.initials_len));
+        let mut locations: SmallVec<[Location; 6]> = SmallVec::new();
+        let mut locations_in_initials: SmallVec<[Location; 4]> = SmallVec::new();
+
+        for part in given {
+            match part {
+                NameWordOrInitial::Word(word, _) => {
+                    let prior_len = text.len();
+                    text.push_str(word);
+                    locations.push(Location::new(prior_len..text.len())?);
+
+                    let prior_len = initials.len();
+                    initials.extend(
+                        word.split('-')
+                            .filter_map(|w| w.chars().find(|c| c.is_alphabetic()))
+                            .filter_map(|c| c.to_uppercase().next()),
+                    );
+                    locations_in_initials.push(Location::new(prior_len..initials.len())?);
+
+                    text.push(' ');
+                }
+                NameWordOrInitial::Initial(c) => {
+                    text.push(c);
+                    text.push_str(". ");
+                    initials.push(c);
+                }
+            }
+        }
+
+        if initials.is_empty() {
+            return None;
+        }
+
+        let surname_start = self.surname_locations()[0].range().start;
+        let offset = text.len();
+        text.push_str(&self.text[surname_start..self.name_bytes()]);
+        for location in self.surname_locations() {
+            let range = location.range();
+            locations.push(Location::new(
+                range.start - surname_start + offset..range.end - surname_start + offset,
+            )?);
+        }
+
+        let given_name_words = locations_in_initials.len().try_into().ok()?;
+        let initials_len = initials.len().try_into().ok()?;
+        text.push_str(&initials);
+        locations.extend_from_slice(&locations_in_initials);
+
+        Some(Name {
+            text,
+            locations,
+            given_name_words,
+            surname_words: self.surname_words,
+            initials_len,
+            generation: self.generation,
+            second_surname_word: self.second_surname_word,
+            surname_order: self.surname_order,
+            flags: self.flags,
+            extras: self.extras.clone(),
+            surname_hash: Default::default(),
+        })
+    }
+
     // Not clear why we have to `always` here but the performance difference is detectable
     // and there's only one caller (though we call this twice)
     #[inline(always)]
@@ -114,6 +464,7 @@ impl Name {
     fn given_names_or_initials(
         &self,
     ) -> GivenNamesOrInitials<
+        '_,
         impl Iterator<Item = (usize, char)> + '_,
         impl Iterator<Item = (&str, Location)> + '_,
     > {
@@ -154,7 +505,7 @@ impl Name {
 
         for my_part in self.given_names_or_initials() {
             if let Some(ref their_part) = their_part_if_any {
-                let result = my_part.check_consistency(their_part, !looked_up_nicknames);
+                let result = my_part.check_consistency(their_part, !looked_up_nicknames, false);
 
                 match result {
                     ComparisonResult::Inconsistent => {
@@ -266,7 +617,7 @@ impl Name {
         my_initials[my_first.len_utf8()..].contains(&their_initials[their_first.len_utf8()..])
     }
 
-    fn transliterated_initials(&self) -> (char, Cow<str>) {
+    fn transliterated_initials(&self) -> (char, Cow<'_, str>) {
         let initials = self.initials();
         if initials.is_ascii() {
             (initials.as_bytes()[0].into(), Cow::Borrowed(initials))
@@ -315,13 +666,54 @@ impl Name {
 
     #[inline]
     fn surname_consistent(&self, other: &Name) -> bool {
-        let mine = self.surname();
-        let theirs = other.surname();
+        if self.second_surname_word == 0 && other.second_surname_word == 0 {
+            Self::surname_strs_consistent(self.surname(), other.surname())
+        } else {
+            self.double_surname_consistent(other)
+        }
+    }
+
+    // We compare the first part of a double surname joined by a conjunction,
+    // as we do when hashing, so "Juan Velasquez" is consistent with "Juan
+    // Velasquez y Garcia". But if both names have double surnames, both parts
+    // must match.
+    #[inline(never)]
+    fn double_surname_consistent(&self, other: &Name) -> bool {
+        let mine = self.text_spanning(self.comparison_surname_locations());
+        let theirs = other.text_spanning(other.comparison_surname_locations());
+        Self::surname_strs_consistent(mine, theirs)
+            && (self.second_surname_word == 0
+                || other.second_surname_word == 0
+                || Self::surname_strs_consistent(self.surname(), other.surname()))
+    }
+
+    // Whether part of either name's double surname is consistent with the
+    // other's whole surname: the paternal part, if we know which that is,
+    // or else either part
+    #[inline(never)]
+    fn double_surname_parts_consistent(&self, other: &Name) -> bool {
+        self.surname_part_consistent(other) || other.surname_part_consistent(self)
+    }
+
+    fn surname_part_consistent(&self, other: &Name) -> bool {
+        let (first, second) = match self.double_surname_locations() {
+            Some(parts) => parts,
+            None => return false,
+        };
+        let part_consistent = |locations| {
+            Self::surname_strs_consistent(self.text_spanning(locations), other.surname())
+        };
+        match self.surname_order {
+            SurnameOrder::PaternalFirst => part_consistent(first),
+            SurnameOrder::MaternalFirst => part_consistent(second),
+            SurnameOrder::Unknown => part_consistent(first) || part_consistent(second),
+        }
+    }
 
+    pub(crate) fn surname_strs_consistent(mine: &str, theirs: &str) -> bool {
         if mine.is_ascii() && theirs.is_ascii() {
-            if self.surname_words == 1
-                && other.surname_words == 1
-                && mine.bytes().all(|b| b.is_ascii_alphabetic())
+            // All-alphabetic implies a single word
+            if mine.bytes().all(|b| b.is_ascii_alphabetic())
                 && theirs.bytes().all(|b| b.is_ascii_alphabetic())
             {
                 return mine.eq_ignore_ascii_case(theirs);
@@ -431,6 +823,8 @@ enum ComparisonResult {
     PrefixOfOther(String),
     PrefixOfSelf(String),
     NicknameMatch,
+    SpellingVariantMatch,
+    EquivalentNameMatch,
 }
 
 impl<'a> NameWordOrInitial<'a> {
@@ -439,6 +833,7 @@ impl<'a> NameWordOrInitial<'a> {
         &self,
         other: &NameWordOrInitial,
         allow_nicknames: bool,
+        allow_equivalents: bool,
     ) -> ComparisonResult {
         #[inline]
         fn fold_initial(c: char) -> char {
@@ -450,9 +845,18 @@ impl<'a> NameWordOrInitial<'a> {
             word.and_then(|w| transliterate::to_ascii_casefolded(&w[c.len_utf8()..]))
         }
 
+        // Another language's form of the name may start differently (e.g.
+        // "Giovanni" for "John")
+        let is_equivalent = || match (self.word(), other.word()) {
+            (Some(mine), Some(theirs)) => allow_equivalents && are_equivalent_names(mine, theirs),
+            _ => false,
+        };
+
         let (my_initial, their_initial) = (self.initial(), other.initial());
         if fold_initial(my_initial) != fold_initial(their_initial) {
-            if self.word().is_some() && other.word().is_some() {
+            if is_equivalent() {
+                return ComparisonResult::EquivalentNameMatch;
+            } else if self.word().is_some() && other.word().is_some() {
                 return ComparisonResult::Inconsistent;
             } else {
                 return ComparisonResult::DifferentInitials;
@@ -487,10 +891,10 @@ impl<'a> NameWordOrInitial<'a> {
             } else if (my_char.is_none() || their_char.is_none())
                 && matched >= MIN_GIVEN_NAME_CHAR_MATCH
             {
-                if their_char.is_some() {
+                if let Some(their_char) = their_char {
                     return ComparisonResult::PrefixOfOther(format!(
                         "{}{}",
-                        their_char.unwrap(),
+                        their_char,
                         their_chars.collect::<String>()
                     ));
                 } else {
@@ -501,10 +905,16 @@ impl<'a> NameWordOrInitial<'a> {
                     ));
                 }
             } else if my_char != their_char {
-                // Failed match; abort, but first, maybe try nickname db
-                if allow_nicknames && have_matching_variants(my_word.unwrap(), their_word.unwrap())
+                // Failed match; abort, but first, maybe try spelling variants,
+                // the nickname db, and other languages' forms of the name
+                if are_spelling_variants(my_word.unwrap(), their_word.unwrap()) {
+                    return ComparisonResult::SpellingVariantMatch;
+                } else if allow_nicknames
+                    && have_matching_variants(my_word.unwrap(), their_word.unwrap())
                 {
                     return ComparisonResult::NicknameMatch;
+                } else if is_equivalent() {
+                    return ComparisonResult::EquivalentNameMatch;
                 } else {
                     return ComparisonResult::Inconsistent;
                 }
@@ -548,7 +958,7 @@ where
     names_and_locations: iter::Peekable<L>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 enum NameWordOrInitial<'a> {
     Word(&'a str, usize),
     Initial(char),
@@ -590,21 +1000,45 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Locale, ParseOptions};
+
+    fn parse(input: &str) -> Name {
+        Name::parse(input).unwrap()
+    }
+
+    fn parse_in(input: &str, locale: Locale) -> Name {
+        Name::parse_with(input, &ParseOptions::new().locale(locale)).unwrap()
+    }
+
+    fn consistent(a: &Name, b: &Name, options: &ComparisonOptions) -> bool {
+        let result = a.consistent_with_options(b, options);
+        assert_eq!(result, b.consistent_with_options(a, options));
+        if result && *options == ComparisonOptions::default() {
+            assert_eq!(a.surname_hash(), b.surname_hash());
+        }
+        result
+    }
 
     #[test]
     fn non_bmp_alphas_simple() {
         let a = NameWordOrInitial::Word("𐒴𐓘", 1);
-        assert_eq!(ComparisonResult::ExactMatch, a.check_consistency(&a, false));
-        assert_eq!(ComparisonResult::ExactMatch, a.check_consistency(&a, true));
+        assert_eq!(
+            ComparisonResult::ExactMatch,
+            a.check_consistency(&a, false, false)
+        );
+        assert_eq!(
+            ComparisonResult::ExactMatch,
+            a.check_consistency(&a, true, false)
+        );
 
         let b = NameWordOrInitial::Word("𐓊𐓙", 1);
         assert_eq!(
             ComparisonResult::Inconsistent,
-            a.check_consistency(&b, false)
+            a.check_consistency(&b, false, false)
         );
         assert_eq!(
             ComparisonResult::Inconsistent,
-            a.check_consistency(&b, true)
+            a.check_consistency(&b, true, false)
         );
 
         assert!(Name::surname_consistent_slow(
@@ -627,21 +1061,21 @@ mod tests {
         let a = NameWordOrInitial::Initial('𐒴');
         assert_eq!(
             ComparisonResult::InitialsOnlyMatch,
-            a.check_consistency(&a, false)
+            a.check_consistency(&a, false, false)
         );
         assert_eq!(
             ComparisonResult::InitialsOnlyMatch,
-            a.check_consistency(&a, true)
+            a.check_consistency(&a, true, false)
         );
 
         let b = NameWordOrInitial::Initial('𐒵');
         assert_eq!(
             ComparisonResult::DifferentInitials,
-            a.check_consistency(&b, true)
+            a.check_consistency(&b, true, false)
         );
         assert_eq!(
             ComparisonResult::DifferentInitials,
-            a.check_consistency(&b, false)
+            a.check_consistency(&b, false, false)
         );
 
         let a = Name::parse("𐒴𐓘 𐓊𐓙").unwrap();
@@ -652,10 +1086,347 @@ mod tests {
         assert!(!b.consistent_with(&c));
     }
 
+    #[test]
+    fn equivalent_name_result() {
+        let giovanni = NameWordOrInitial::Word("Giovanni", 1);
+        let john = NameWordOrInitial::Word("John", 1);
+        assert_eq!(
+            ComparisonResult::EquivalentNameMatch,
+            giovanni.check_consistency(&john, true, true)
+        );
+        assert_eq!(
+            ComparisonResult::Inconsistent,
+            giovanni.check_consistency(&john, true, false)
+        );
+
+        let juan = NameWordOrInitial::Word("Juan", 1);
+        assert_eq!(
+            ComparisonResult::EquivalentNameMatch,
+            juan.check_consistency(&john, false, true)
+        );
+
+        let jim = NameWordOrInitial::Word("Jim", 1);
+        let james = NameWordOrInitial::Word("James", 1);
+        assert_eq!(
+            ComparisonResult::NicknameMatch,
+            jim.check_consistency(&james, true, true)
+        );
+    }
+
     #[test]
     fn bug() {
         let a = Name::parse("Peter Martin-Le Bore").unwrap();
         let b = Name::parse("Peter Martin-Le Bore").unwrap();
         assert!(a.consistent_with(&b));
     }
+
+    #[test]
+    fn double_surnames() {
+        let options = ComparisonOptions::default();
+        // The same input is consistent whatever the locale
+        let full = parse_in("Gabriel García Márquez", Locale::Es);
+        assert!(consistent(
+            &full,
+            &parse_in("Gabriel García Márquez", Locale::Any),
+            &options
+        ));
+        assert!(consistent(
+            &full,
+            &parse_in("G. García Márquez", Locale::Es),
+            &options
+        ));
+        assert!(consistent(
+            &full,
+            &parse_in("Gabriel Márquez", Locale::Es),
+            &options
+        ));
+        assert!(!consistent(
+            &full,
+            &parse_in("Gabriel García", Locale::Es),
+            &options
+        ));
+        assert!(!consistent(
+            &full,
+            &parse_in("Gabriel García López", Locale::Es),
+            &options
+        ));
+
+        let full = parse_in("Maria da Silva Santos", Locale::Pt);
+        assert!(consistent(
+            &full,
+            &parse_in("Maria Santos", Locale::Pt),
+            &options
+        ));
+        assert!(!consistent(
+            &full,
+            &parse_in("Maria da Silva", Locale::Pt),
+            &options
+        ));
+
+        let full = parse_in("Iria del Río Gayo", Locale::Any);
+        assert!(consistent(
+            &full,
+            &parse_in("Iria Gayo", Locale::Any),
+            &options
+        ));
+        assert!(consistent(
+            &full,
+            &parse_in("Iria del Río Gayo", Locale::Es),
+            &options
+        ));
+        assert!(!consistent(
+            &full,
+            &parse_in("Iria del Río", Locale::Any),
+            &options
+        ));
+
+        // A conjunction makes the parts clear
+        let full = parse_in("Juan Velasquez y Garcia", Locale::Any);
+        assert!(consistent(
+            &full,
+            &parse_in("Juan Velasquez", Locale::Any),
+            &options
+        ));
+        assert!(consistent(
+            &full,
+            &parse_in("Juan Velasquez y Garcia", Locale::Es),
+            &options
+        ));
+        assert!(!consistent(
+            &full,
+            &parse_in("Juan Garcia", Locale::Any),
+            &options
+        ));
+        assert!(!consistent(
+            &full,
+            &parse_in("Juan Velasquez y Lopez", Locale::Any),
+            &options
+        ));
+    }
+
+    #[test]
+    fn double_surname_parts() {
+        let options = ComparisonOptions::new().match_double_surname_parts(true);
+
+        let full = parse_in("Gabriel García Márquez", Locale::Es);
+        assert!(consistent(
+            &full,
+            &parse_in("Gabriel García", Locale::Es),
+            &options
+        ));
+        assert!(consistent(
+            &full,
+            &parse_in("Gabriel García", Locale::Any),
+            &options
+        ));
+        assert!(!consistent(
+            &full,
+            &parse_in("Gabriel López", Locale::Es),
+            &options
+        ));
+
+        let full = parse_in("Maria da Silva Santos", Locale::Pt);
+        assert!(!consistent(
+            &full,
+            &parse_in("Maria da Silva", Locale::Pt),
+            &options
+        ));
+
+        // Without a locale, we don't know which part is paternal
+        let full = parse_in("Juan Velasquez y Garcia", Locale::Any);
+        assert!(consistent(
+            &full,
+            &parse_in("Juan Garcia", Locale::Any),
+            &options
+        ));
+        assert!(consistent(
+            &full,
+            &parse_in("Juan Velasquez", Locale::Any),
+            &options
+        ));
+        assert!(!consistent(
+            &full,
+            &parse_in("Pedro Garcia", Locale::Any),
+            &options
+        ));
+    }
+
+    #[test]
+    fn fallbacks_keep_locale() {
+        let options = ComparisonOptions::default();
+        // Each fallback compares the rest of the name as parsed, rather than
+        // as it would be parsed by default
+        let full = parse_in("Abu Bakr Muhammad ibn Zakariya al-Razi", Locale::Ar);
+        let without_patronymic = parse_in("Abu Bakr Muhammad al-Razi", Locale::Ar);
+        assert!(consistent(&full, &without_patronymic, &options));
+
+        let full = parse_in("Gabriel 'Gabo' José García Márquez", Locale::Es);
+        assert!(consistent(
+            &full,
+            &parse_in("Gabo José García Márquez", Locale::Es),
+            &options
+        ));
+        assert!(!consistent(
+            &full,
+            &parse_in("Gabo Luis García Márquez", Locale::Es),
+            &options
+        ));
+    }
+
+    #[test]
+    fn romanized_surnames() {
+        let options = ComparisonOptions::new().match_romanized_surnames(true);
+
+        assert!(consistent(
+            &parse("Wei Zhang"),
+            &parse("Wei Chang"),
+            &options
+        ));
+        assert!(consistent(
+            &parse("Wei Zhang"),
+            &parse("W. Cheung"),
+            &options
+        ));
+        assert!(consistent(
+            &parse("Min-jun Lee"),
+            &parse("Min-jun Rhee"),
+            &options
+        ));
+        assert!(consistent(&parse("Min-jun Yi"), &parse("M. Lee"), &options));
+        assert!(consistent(
+            &parse("Hye-jin Choi"),
+            &parse("Hye-jin Choe"),
+            &options
+        ));
+        assert!(!consistent(
+            &parse("Wei Zhang"),
+            &parse("Wei Lee"),
+            &options
+        ));
+        assert!(!consistent(
+            &parse("Wei Zhang"),
+            &parse("Li Chang"),
+            &options
+        ));
+        assert!(!consistent(
+            &parse("Wei Zhang"),
+            &parse("Wei Smith-Chang"),
+            &options
+        ));
+
+        // Including in Chinese characters
+        let han_chars = Name::parse_with("張偉", &ParseOptions::new().locale(Locale::Zh)).unwrap();
+        let cheung = Name::parse("Wei Cheung").unwrap();
+        assert!(han_chars.consistent_with_options(&cheung, &options));
+
+        // Only with the option
+        let zhang = Name::parse("Wei Zhang").unwrap();
+        let chang = Name::parse("Wei Chang").unwrap();
+        assert!(!zhang.consistent_with(&chang));
+    }
+
+    #[test]
+    fn equivalent_names() {
+        let options = ComparisonOptions::new().match_equivalent_names(true);
+
+        assert!(consistent(
+            &parse("Johann Sebastian Bach"),
+            &parse("John S. Bach"),
+            &options
+        ));
+        assert!(consistent(
+            &parse("Pyotr Ilyich Tchaikovsky"),
+            &parse("Peter Tchaikovsky"),
+            &options
+        ));
+        assert!(consistent(
+            &parse("Giovanni Rossi"),
+            &parse("Ivan Rossi"),
+            &options
+        ));
+        assert!(consistent(
+            &parse("Jacques Cartier"),
+            &parse("James Cartier"),
+            &options
+        ));
+        assert!(consistent(
+            &parse("Jacques Cartier"),
+            &parse("Jacob Cartier"),
+            &options
+        ));
+        assert!(consistent(
+            &parse("Guillermo Jorge Smith"),
+            &parse("William George Smith"),
+            &options
+        ));
+        assert!(!consistent(
+            &parse("James Cartier"),
+            &parse("Jacob Cartier"),
+            &options
+        ));
+        assert!(!consistent(
+            &parse("Guillermo Jorge Smith"),
+            &parse("William Henry Smith"),
+            &options
+        ));
+        assert!(!consistent(
+            &parse("Juan Smith"),
+            &parse("Juan Jones"),
+            &options
+        ));
+        assert!(!consistent(
+            &parse("Juan Smith"),
+            &parse("Joseph Smith"),
+            &options
+        ));
+
+        // Only with the option
+        let giovanni = Name::parse("Giovanni Rossi").unwrap();
+        assert!(!giovanni.consistent_with(&Name::parse("John Rossi").unwrap()));
+    }
+
+    #[cfg(all(feature = "nicknames-es", feature = "nicknames-ru"))]
+    #[test]
+    fn locale_nicknames() {
+        let options = ComparisonOptions::new()
+            .match_nicknames_from(Locale::Es)
+            .match_nicknames_from(Locale::Ru);
+
+        assert!(consistent(
+            &parse("José García"),
+            &parse("Pepe García"),
+            &options
+        ));
+        assert!(consistent(
+            &parse("Francisco José García"),
+            &parse("Paco Pepe García"),
+            &options
+        ));
+        assert!(consistent(
+            &parse("Francisco J. García"),
+            &parse("Paco García"),
+            &options
+        ));
+        assert!(consistent(
+            &parse("Aleksandr Sergeyevich Pushkin"),
+            &parse("Sasha Pushkin"),
+            &options
+        ));
+        assert!(!consistent(
+            &parse("José García"),
+            &parse("Paco García"),
+            &options
+        ));
+        assert!(!consistent(
+            &parse("Aleksandr Pushkin"),
+            &parse("Misha Pushkin"),
+            &options
+        ));
+
+        // Only for the languages requested
+        let options = ComparisonOptions::new().match_nicknames_from(Locale::It);
+        let jose = Name::parse("José García").unwrap();
+        let pepe = Name::parse("Pepe García").unwrap();
+        assert!(!jose.consistent_with_options(&pepe, &options));
+    }
 }
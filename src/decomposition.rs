@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::ops::Range;
 use unicode_normalization::char::canonical_combining_class;
 use unicode_normalization::{is_nfkd_quick, IsNormalized, UnicodeNormalization};
 
@@ -21,7 +22,7 @@ fn do_normalize(string: &str) -> String {
         .collect()
 }
 
-pub fn normalize_nfkd_whitespace(string: &str) -> Cow<str> {
+pub fn normalize_nfkd_whitespace(string: &str) -> Cow<'_, str> {
     if already_normalized(string) {
         Cow::Borrowed(string)
     } else {
@@ -29,6 +30,23 @@ pub fn normalize_nfkd_whitespace(string: &str) -> Cow<str> {
     }
 }
 
+// For each byte of the output of `normalize_nfkd_whitespace`, the range of
+// the input character it came from. (Canonical reordering only moves
+// combining marks within a run, so decomposing character by character gives
+// the same character boundaries.)
+pub fn input_ranges(string: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::with_capacity(string.len());
+    for (i, c) in string.char_indices() {
+        let len: usize = if c.is_whitespace() {
+            1
+        } else {
+            Some(c).into_iter().nfkd().map(char::len_utf8).sum()
+        };
+        ranges.extend((0..len).map(|_| i..i + c.len_utf8()));
+    }
+    ranges
+}
+
 #[inline]
 pub fn is_combining(c: char) -> bool {
     canonical_combining_class(c) > 0
@@ -0,0 +1,59 @@
+use std::error::Error;
+use std::fmt;
+
+/// Explains why a string could not be parsed as a `Name`.
+///
+/// Each variant corresponds to the stage of parsing at which we gave up,
+/// and carries the (raw, NFKD-normalized) tokens we were looking at when we
+/// did, so that callers can route different kinds of failure differently.
+///
+/// ```
+/// use human_name::{Name, ParseError};
+///
+/// assert_eq!(
+///     Err(ParseError::SingleWord { word: "Doe".to_string() }),
+///     Name::try_parse("Mr. Doe").map(|n| n.display_full().into_owned())
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The input was at least `MAX_NAME_LEN` bytes long (or the `max_len`
+    /// set in `ParseOptions`), or produced a name too long to represent.
+    TooLong { len: usize },
+
+    /// The input contained no words with alphabetical characters.
+    NoWords,
+
+    /// Every word in the input was stripped as an honorific prefix, honorific
+    /// suffix, or generational suffix.
+    OnlyTitles { titles: Vec<String> },
+
+    /// Only one plausible name word was left after stripping titles and
+    /// suffixes (single-word names are not supported by `Name`, but see
+    /// `PersonName`).
+    SingleWord { word: String },
+
+    /// Several words were left after stripping titles and suffixes, but they
+    /// do not look like a name (e.g. some are junk, or no word after the
+    /// given name or initials looks like a surname).
+    Implausible { words: Vec<String> },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::TooLong { len } => write!(f, "input too long ({} bytes)", len),
+            ParseError::NoWords => write!(f, "no words found"),
+            ParseError::OnlyTitles { titles } => {
+                write!(f, "only titles found: {}", titles.join(" "))
+            }
+            ParseError::SingleWord { word } => write!(f, "only one name word found: {}", word),
+            ParseError::Implausible { words } => {
+                write!(f, "words do not form a plausible name: {}", words.join(" "))
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
@@ -104,6 +104,11 @@ pub unsafe extern "C" fn human_name_honorific_suffix(name: &Name) -> *const c_ch
     option_str_to_char_star!(name.honorific_suffix())
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn human_name_nickname(name: &Name) -> *const c_char {
+    option_str_to_char_star!(name.nickname())
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn human_name_display_first_last(name: &Name) -> *const c_char {
     str_to_char_star!(name.display_first_last().into_owned())
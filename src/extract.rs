@@ -0,0 +1,313 @@
+//! Finding names in free text, such as email signatures or document headers.
+//!
+//! Unlike `Name::parse`, which tries hard to read any input as a name, this
+//! module looks for names only where there is some evidence of one: a known
+//! honorific or generational suffix, a surname particle, or a given name
+//! from our nickname data.
+
+use super::namepart::{Location, NamePart};
+use super::nickname;
+use super::options::Locale;
+use super::suffix;
+use super::surname;
+use super::title;
+use super::Name;
+use std::ops::Range;
+
+// Longest run of words we'll try to parse, not counting prefix titles
+const MAX_NAME_WORDS: usize = 5;
+
+#[derive(Debug)]
+struct Token {
+    span: Range<usize>,
+    // Whether the token was followed by a comma, which only continues a name
+    // before a suffix, as in "John Doe, Jr."
+    comma_after: bool,
+    // Whether the token ended a sentence or clause
+    break_after: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Anchors {
+    prefix: bool,
+    given_name: bool,
+    particle: bool,
+    suffix: bool,
+}
+
+/// Finds likely person names in free text, returning each one's byte range
+/// in the text along with the parsed `Name`.
+///
+/// Looks for runs of capitalized words (allowing lowercase surname particles
+/// like "van" or "de", and initials) on a single line, which include a
+/// known honorific prefix or suffix, a generational suffix, a surname
+/// particle, or a known given name, and keeps those which `Name::parse`
+/// accepts. Leading words before a title or known given name (e.g.
+/// "Thanks" in "Thanks Dr. Jane Doe") are dropped.
+///
+/// This is heuristic, and biased towards precision: names with unfamiliar
+/// given names and no titles will be missed, and capitalized words which
+/// follow a name may be taken as part of it.
+///
+/// # Examples
+/// ```
+/// use human_name::extract::find_names;
+///
+/// let text = "Best regards,\nDr. Jane Doe\nAcme Corp\n\ncc: John Q. Public, Jr.";
+/// let names: Vec<_> = find_names(text).collect();
+/// assert_eq!(2, names.len());
+///
+/// let (range, name) = &names[0];
+/// assert_eq!("Dr. Jane Doe", &text[range.clone()]);
+/// assert_eq!("Doe", name.surname());
+///
+/// let (range, name) = &names[1];
+/// assert_eq!("John Q. Public, Jr.", &text[range.clone()]);
+/// assert_eq!("John Q. Public, Jr.", name.display_full());
+/// ```
+pub fn find_names(text: &str) -> impl Iterator<Item = (Range<usize>, Name)> {
+    let mut names = Vec::new();
+
+    let mut line_start = 0;
+    for line in text.split('\n') {
+        let tokens = tokenize(line, line_start);
+        line_start += line.len() + 1;
+
+        let mut i = 0;
+        while i < tokens.len() {
+            let run_len = name_run_len(text, &tokens[i..]);
+            if run_len == 0 {
+                i += 1;
+                continue;
+            }
+
+            let run = &tokens[i..i + run_len];
+            if let Some(found) = parse_run(text, run) {
+                names.push(found);
+            }
+            i += run_len;
+        }
+    }
+
+    names.into_iter()
+}
+
+// Splits on whitespace, trimming quotes, brackets, and punctuation other
+// than periods (which may belong to abbreviations)
+fn tokenize(line: &str, offset: usize) -> Vec<Token> {
+    let mut tokens = Vec::new();
+
+    let mut word_start = None;
+    for (i, c) in line.char_indices().chain(Some((line.len(), ' '))) {
+        match (c.is_whitespace(), word_start) {
+            (false, None) => word_start = Some(i),
+            (true, Some(start)) => {
+                word_start = None;
+
+                let word = &line[start..i];
+                let trimmed_start = word.trim_start_matches(is_leading_punctuation);
+                let trimmed = trimmed_start.trim_end_matches(is_trailing_punctuation);
+                if trimmed.is_empty() {
+                    // E.g. a dash; it doesn't belong in a name
+                    if let Some(prior) = tokens.last_mut() {
+                        let prior: &mut Token = prior;
+                        prior.break_after = true;
+                    }
+                    continue;
+                }
+
+                let start = offset + start + (word.len() - trimmed_start.len());
+                let trailing = &trimmed_start[trimmed.len()..];
+                tokens.push(Token {
+                    span: start..start + trimmed.len(),
+                    comma_after: trailing.starts_with(','),
+                    break_after: trailing.contains(|c| c != ',' && c != '\'' && c != '"')
+                        || trailing.len() > 1,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    tokens
+}
+
+fn is_leading_punctuation(c: char) -> bool {
+    "\"'“‘«([<".contains(c)
+}
+
+fn is_trailing_punctuation(c: char) -> bool {
+    "\"'”’»)]>,;:!?".contains(c)
+}
+
+// How many tokens, starting with the first, might make up a name
+fn name_run_len(text: &str, tokens: &[Token]) -> usize {
+    let mut len = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        let word = &text[token.span.clone()];
+        let first = i == 0;
+        if !might_be_name_word(word, first) {
+            break;
+        }
+
+        len += 1;
+
+        if token.break_after || ends_sentence(word) {
+            break;
+        }
+        if token.comma_after {
+            // Continue only into a suffix, as in "John Doe, Jr." or "Jane
+            // Doe, PhD"
+            match tokens.get(i + 1) {
+                Some(next) if is_suffix(&text[next.span.clone()]) => {}
+                _ => break,
+            }
+        }
+    }
+    len
+}
+
+fn might_be_name_word(word: &str, first: bool) -> bool {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(c) if c.is_uppercase() => word
+            .chars()
+            .all(|c| c.is_alphabetic() || ".'-’".contains(c)),
+        Some(c) if c.is_lowercase() => !first && surname::is_surname_prefix(word, Locale::Any),
+        _ => false,
+    }
+}
+
+// A period after a full word (rather than an abbreviation or initial) ends
+// a sentence, as in "I met Jane Doe."
+fn ends_sentence(word: &str) -> bool {
+    let without_period = match word.strip_suffix('.') {
+        Some(without_period) => without_period,
+        None => return false,
+    };
+    without_period.chars().count() > 2
+        && !without_period.contains('.')
+        && !title::is_known_prefix(word)
+        && !is_suffix(word)
+}
+
+// Generational suffixes like "Jr." and "Sr." double as titles elsewhere
+fn is_title(word: &str) -> bool {
+    title::is_known_prefix(word) && !is_generation(word)
+}
+
+fn is_suffix(word: &str) -> bool {
+    is_generation(word) || title::is_known_suffix(word)
+}
+
+fn is_generation(word: &str) -> bool {
+    let part = NamePart::from_word(word, true, Location::End);
+    suffix::generation_from_suffix(&part, false).is_some()
+}
+
+fn find_anchors(words: &[&str]) -> Anchors {
+    let mut anchors = Anchors::default();
+    for (i, word) in words.iter().enumerate() {
+        let last = i == words.len() - 1;
+        if !last && is_title(word) {
+            anchors.prefix = true;
+        } else if i > 0 && is_suffix(word) {
+            anchors.suffix = true;
+        } else if i > 0 && !last && surname::is_surname_prefix(word, Locale::Any) {
+            anchors.particle = true;
+        } else if nickname::is_known_given_name(word) {
+            anchors.given_name = true;
+        }
+    }
+    anchors
+}
+
+fn parse_run(text: &str, run: &[Token]) -> Option<(Range<usize>, Name)> {
+    let words: Vec<&str> = run.iter().map(|t| &text[t.span.clone()]).collect();
+
+    // Drop leading words which come before any title or known given name
+    // (e.g. "Regards"), as long as there's something left to anchor the name
+    let start = words
+        .iter()
+        .position(|w| is_title(w))
+        .or_else(|| words.iter().position(|w| nickname::is_known_given_name(w)))
+        .unwrap_or(0);
+    let prefix_len = words[start..].iter().take_while(|w| is_title(w)).count();
+    let end = words.len().min(start + prefix_len + MAX_NAME_WORDS);
+    if end - start < 2 {
+        return None;
+    }
+
+    let anchors = find_anchors(&words[start..end]);
+    if !(anchors.prefix || anchors.given_name || anchors.particle || anchors.suffix) {
+        return None;
+    }
+
+    let range_start = run[start].span.start;
+    let last = &words[end - 1];
+    let range_end = if ends_sentence(last) {
+        run[end - 1].span.end - 1
+    } else {
+        run[end - 1].span.end
+    };
+    let range = range_start..range_end;
+
+    let name = Name::parse(&text[range.clone()])?;
+    Some((range, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(text: &str) -> Vec<&str> {
+        find_names(text).map(|(range, _)| &text[range]).collect()
+    }
+
+    #[test]
+    fn signatures() {
+        assert_eq!(
+            vec!["John Smith"],
+            find("Thanks,\nJohn Smith\nSenior Engineer | Acme Corp")
+        );
+        assert_eq!(
+            vec!["Dr. Jane Doe, MD"],
+            find("Sincerely, Dr. Jane Doe, MD\n555-1234")
+        );
+        assert_eq!(vec!["Mary Jones"], find("Regards Mary Jones"));
+    }
+
+    #[test]
+    fn prose() {
+        assert_eq!(
+            vec!["Ludwig van Beethoven", "Jane Doe"],
+            find("A letter from Ludwig van Beethoven to Jane Doe.")
+        );
+        assert_eq!(
+            vec!["Prof. Ada Okonkwo"],
+            find("Ask Prof. Ada Okonkwo; she knows.")
+        );
+
+        // Not enough to parse as a `Name`
+        assert!(find("Ask Prof. Okonkwo; she knows.").is_empty());
+        assert_eq!(
+            vec!["Martin Luther King, Jr."],
+            find("\"I have a dream\" - Martin Luther King, Jr.")
+        );
+    }
+
+    #[test]
+    fn anchors() {
+        // No known given name, title, or particle
+        assert!(find("Zorblax Quuxington").is_empty());
+        assert_eq!(
+            vec!["Mr. Zorblax Quuxington"],
+            find("Mr. Zorblax Quuxington")
+        );
+
+        // Capitalized, but not names
+        assert!(find("The Quick Brown Fox").is_empty());
+        assert!(find("Dear Sir, hello").is_empty());
+        assert!(find("").is_empty());
+    }
+}
@@ -7,7 +7,7 @@ pub struct CharacterCounts {
 }
 
 pub fn categorize_chars(word: &str) -> CharacterCounts {
-    debug_assert!(word.len() <= u8::max_value() as usize);
+    debug_assert!(word.len() <= u8::MAX as usize);
 
     let mut chars = 0;
     let mut alpha = 0;
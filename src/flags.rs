@@ -0,0 +1,151 @@
+use std::fmt;
+use std::ops::{BitOr, BitOrAssign};
+
+/// A set of flags recording which fallback heuristics the parser relied on
+/// to produce a `Name`.
+///
+/// An empty set means the input was unambiguous as far as the parser could
+/// tell. Each flag lowers the `confidence` of the parse.
+///
+/// ```
+/// use human_name::{Name, ParseFlags};
+///
+/// let name = Name::parse("Jane Doe").unwrap();
+/// assert!(name.parse_flags().is_empty());
+///
+/// let name = Name::parse("John Esq").unwrap();
+/// assert_eq!("Esq", name.surname());
+/// assert!(name.parse_flags().contains(ParseFlags::FALSE_POSTFIX));
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ParseFlags(u16);
+
+impl ParseFlags {
+    /// A word that looked like an honorific suffix (e.g. "Esq" in "John Esq")
+    /// was treated as part of the name, because otherwise there would not
+    /// have been enough words left.
+    pub const FALSE_POSTFIX: ParseFlags = ParseFlags(1 << 0);
+
+    /// A word that looked like an honorific prefix (e.g. "Dr" in "Dr Smith")
+    /// was treated as part of the name, because otherwise there would not
+    /// have been enough words left.
+    pub const FALSE_PREFIX: ParseFlags = ParseFlags(1 << 1);
+
+    /// Trailing words that did not look like names (usually initials) were
+    /// discarded as stray postfixes.
+    pub const DISCARDED_POSTFIX: ParseFlags = ParseFlags(1 << 2);
+
+    /// A word was re-interpreted as a name after ignoring its capitalization
+    /// (e.g. the all-caps surname in "John NG", which would otherwise look
+    /// like initials).
+    pub const IGNORED_CAPITALIZATION: ParseFlags = ParseFlags(1 << 3);
+
+    /// Our initial guess at which words formed the surname (e.g. that the part
+    /// before a comma was only a surname) turned out to be wrong, and we fell
+    /// back to guessing the surname from word order alone.
+    pub const REVISED_SURNAME: ParseFlags = ParseFlags(1 << 4);
+
+    /// A word of more than one letter without periods (e.g. "JM" or "AL")
+    /// was treated as initials rather than a given or middle name.
+    pub const GUESSED_INITIALS: ParseFlags = ParseFlags(1 << 5);
+
+    const NAMES_AND_PENALTIES: [(ParseFlags, &'static str, f32); 6] = [
+        (ParseFlags::FALSE_POSTFIX, "FALSE_POSTFIX", 0.7),
+        (ParseFlags::FALSE_PREFIX, "FALSE_PREFIX", 0.7),
+        (ParseFlags::DISCARDED_POSTFIX, "DISCARDED_POSTFIX", 0.8),
+        (
+            ParseFlags::IGNORED_CAPITALIZATION,
+            "IGNORED_CAPITALIZATION",
+            0.8,
+        ),
+        (ParseFlags::REVISED_SURNAME, "REVISED_SURNAME", 0.8),
+        (ParseFlags::GUESSED_INITIALS, "GUESSED_INITIALS", 0.9),
+    ];
+
+    /// The empty set of flags.
+    #[inline]
+    pub const fn empty() -> ParseFlags {
+        ParseFlags(0)
+    }
+
+    /// Were no fallback heuristics needed?
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Are all the flags in `other` also set in `self`?
+    #[inline]
+    pub const fn contains(self, other: ParseFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// A score in `(0, 1]` reflecting how much we had to guess, where 1.0
+    /// means no fallback heuristics were needed.
+    ///
+    /// This is not a calibrated probability; it is only useful for ranking
+    /// or thresholding parses relative to one another.
+    pub fn confidence(self) -> f32 {
+        ParseFlags::NAMES_AND_PENALTIES
+            .iter()
+            .filter(|(flag, _, _)| self.contains(*flag))
+            .map(|(_, _, penalty)| penalty)
+            .product()
+    }
+}
+
+impl BitOr for ParseFlags {
+    type Output = ParseFlags;
+
+    #[inline]
+    fn bitor(self, other: ParseFlags) -> ParseFlags {
+        ParseFlags(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for ParseFlags {
+    #[inline]
+    fn bitor_assign(&mut self, other: ParseFlags) {
+        self.0 |= other.0;
+    }
+}
+
+impl fmt::Debug for ParseFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set()
+            .entries(
+                ParseFlags::NAMES_AND_PENALTIES
+                    .iter()
+                    .filter(|(flag, _, _)| self.contains(*flag))
+                    .map(|(_, name, _)| name),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn confidence() {
+        assert_eq!(1.0, ParseFlags::empty().confidence());
+        assert_eq!(0.7, ParseFlags::FALSE_POSTFIX.confidence());
+        assert!(
+            (ParseFlags::FALSE_POSTFIX | ParseFlags::GUESSED_INITIALS).confidence()
+                < ParseFlags::FALSE_POSTFIX.confidence()
+        );
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!("{}", format!("{:?}", ParseFlags::empty()));
+        assert_eq!(
+            r#"{"FALSE_PREFIX", "GUESSED_INITIALS"}"#,
+            format!(
+                "{:?}",
+                ParseFlags::GUESSED_INITIALS | ParseFlags::FALSE_PREFIX
+            )
+        );
+    }
+}
@@ -18,15 +18,29 @@ extern crate test;
 #[cfg(test)]
 extern crate alloc_counter;
 
+mod arabic;
+mod authors;
 mod case;
+mod cjk;
 mod comparison;
 mod decomposition;
+mod error;
+pub mod extract;
 mod features;
+mod flags;
+mod multiple;
 mod namecase;
 mod namepart;
 mod nickname;
+mod options;
+mod parenthetical;
 mod parse;
+mod partial;
+mod patronymic;
+mod person;
+mod romanization;
 mod segment;
+mod spans;
 mod suffix;
 mod surname;
 mod title;
@@ -42,12 +56,24 @@ mod eq_hash;
 #[cfg(feature = "serialization")]
 mod serialization;
 
+pub use crate::authors::parse_author_list;
+pub use crate::error::ParseError;
+pub use crate::flags::ParseFlags;
+pub use crate::multiple::parse_all;
+pub use crate::options::{ComparisonOptions, Locale, NameOrder, ParseOptions};
+pub use crate::parenthetical::{Parenthetical, ParentheticalKind};
+pub use crate::partial::{parse_partial, PartialName};
+pub use crate::person::{Mononym, PersonName};
+pub use crate::spans::NameSpans;
+
 use crate::decomposition::normalize_nfkd_whitespace;
+use crate::surname::SurnameOrder;
 use crate::word::{Location, Words};
 use compact_str::CompactString;
 use crossbeam_utils::atomic::AtomicCell;
 use smallvec::SmallVec;
 use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::collections::hash_map::DefaultHasher;
 use std::convert::TryInto;
 use std::hash::{Hash, Hasher};
@@ -89,14 +115,21 @@ pub struct Name {
     surname_words: u8,                  // support no more than 256
     initials_len: u8,                   // support no more than 256
     generation: Option<NonZeroU8>,
-    honorifics: Option<Box<Honorifics>>,
+    second_surname_word: u8, // index in surname words, or 0 if none
+    surname_order: SurnameOrder,
+    flags: ParseFlags,
+    extras: Option<Box<Extras>>,
     surname_hash: AtomicCell<Option<u32>>,
 }
 
 #[derive(Clone, Debug)]
-struct Honorifics {
-    prefix: Option<Box<str>>,
-    suffix: Option<Box<str>>,
+struct Extras {
+    honorific_prefix: Option<Box<str>>,
+    honorific_suffix: Option<Box<str>>,
+    birth_surname: Option<Box<str>>,
+    patronymic: Option<Box<str>>,
+    kunya: Option<Box<str>>,
+    parentheticals: Box<[Parenthetical]>,
 }
 
 impl Clone for Name {
@@ -108,7 +141,10 @@ impl Clone for Name {
             surname_words: self.surname_words,
             initials_len: self.initials_len,
             generation: self.generation,
-            honorifics: self.honorifics.clone(),
+            second_surname_word: self.second_surname_word,
+            surname_order: self.surname_order,
+            flags: self.flags,
+            extras: self.extras.clone(),
             surname_hash: Default::default(),
         }
     }
@@ -146,7 +182,12 @@ impl Name {
     /// order ("Doe, Jane"). Makes use of heuristics based on case when
     /// applicable (e.g., "AL Doe" is parsed as "A. L. Doe", while "Al Doe" is
     /// parsed as a given name and surname), as well as _small_ sets of known
-    /// particles, conjunctions, titles, etc.
+    /// particles, conjunctions, titles, etc. In mixed-case input, a single
+    /// all-caps run of words is taken to be the surname wherever it appears
+    /// (e.g., "DUPONT Jean Pierre" is parsed as "Jean Pierre Dupont").
+    /// Bibliographic "Surname INITIALS" order, as used by Vancouver-style
+    /// citations and PubMed, can also be recognized (e.g., "Smith JA" can be
+    /// parsed as "J. A. Smith"; see `ParseOptions::initials_after_surname`).
     ///
     /// # Limitations
     ///
@@ -156,19 +197,25 @@ impl Name {
     ///
     /// However, success requires at least an apparent surname and first initial.
     /// Single-word names cannot be parsed (you may or may not wish to assume
-    /// they are given names).
+    /// they are given names; use `PersonName` to accept them as mononyms).
+    /// Nor can names lacking a given name or initial, like "Mrs. Jones" (but
+    /// see `parse_partial`). Use `try_parse` to find out why parsing failed.
     ///
-    /// Does not preserve titles (other than generational suffixes such as "III")
-    /// or nicknames. Does not handle plural forms specially: "Mr. & Mrs. John
-    /// Doe" will be parsed as "John Doe", and "Jane Doe, et al" will be parsed
-    /// as "Jane Doe".
+    /// Titles (other than generational suffixes such as "III") and nicknames
+    /// are available from their own accessors, but are not part of the
+    /// canonical representation used by `display_full`. Does not handle plural forms specially: "Mr. & Mrs. John
+    /// Doe" will be parsed as "John Doe" (use `parse_all` to get a name for
+    /// each person), and "Jane Doe, et al" will be parsed as "Jane Doe".
     ///
     /// Works best on Latin names - i.e., data from North or South America or
     /// Europe. Does not understand surname-first formats without commas: "Kim
-    /// Il-sung" will be parsed as having the first name "Kim".
+    /// Il-sung" will be parsed as having the first name "Kim" (although
+    /// `parse_candidates` will include the alternative). If you know your
+    /// input puts surnames first, use `parse_with` and `NameOrder::SurnameFirst`.
     ///
-    /// Handles non-Latin unicode strings, but without any particular intelligence.
-    /// Attempts at least to fail nicely, such that either `parse` returns `None`,
+    /// Handles non-Latin unicode strings, but without any particular intelligence
+    /// (except for Chinese, Japanese, and Korean names parsed with the
+    /// corresponding `Locale`). Attempts at least to fail nicely, such that either `parse` returns `None`,
     /// or calling `display_full()` on the parsed result returns the input,
     /// plus or minus whitespace.
     ///
@@ -176,25 +223,237 @@ impl Name {
     /// for canonicalizing names. The goal here is to do the best we can without
     /// large statistical models.
     pub fn parse(name: &str) -> Option<Name> {
-        if name.len() >= MAX_NAME_LEN {
-            return None;
-        }
+        Name::try_parse(name).ok()
+    }
+
+    /// Like `parse`, but on failure, explains at which stage parsing failed
+    /// and which tokens were involved.
+    ///
+    /// # Examples
+    /// ```
+    /// use human_name::{Name, ParseError};
+    ///
+    /// let name = Name::try_parse("Jane Doe").unwrap();
+    /// assert_eq!("Doe", name.surname());
+    ///
+    /// assert_eq!(Some(ParseError::NoWords), Name::try_parse("12345").err());
+    ///
+    /// assert_eq!(
+    ///     Some(ParseError::OnlyTitles { titles: vec!["Dr.".to_string(), "PhD".to_string()] }),
+    ///     Name::try_parse("Dr., PhD").err()
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Some(ParseError::SingleWord { word: "Cher".to_string() }),
+    ///     Name::try_parse("Cher").err()
+    /// );
+    /// ```
+    pub fn try_parse(name: &str) -> Result<Name, ParseError> {
+        Name::try_parse_with(name, &ParseOptions::default())
+    }
+
+    /// Like `parse`, but allows overriding our default heuristics (see
+    /// `ParseOptions`).
+    ///
+    /// # Examples
+    /// ```
+    /// use human_name::{Locale, Name, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().locale(Locale::Es);
+    /// let name = Name::parse_with("Dato e Iradier, Eduardo", &options).unwrap();
+    /// assert_eq!("Dato e Iradier", name.surname());
+    ///
+    /// let options = ParseOptions::new().max_len(8);
+    /// assert!(Name::parse_with("Jane Doe", &options).is_none());
+    /// ```
+    pub fn parse_with(name: &str, options: &ParseOptions) -> Option<Name> {
+        Name::try_parse_with(name, options).ok()
+    }
+
+    /// Like `parse_with`, but on failure, explains at which stage parsing
+    /// failed and which tokens were involved (see `try_parse`).
+    pub fn try_parse_with(name: &str, options: &ParseOptions) -> Result<Name, ParseError> {
+        preprocess(name, options, |input| {
+            let parsed = parse::parse_with(input.stripped, options, Default::default())?;
+            input.initialize_struct(&parsed).ok_or(ParseError::TooLong {
+                len: input.stripped.len(),
+            })
+        })
+    }
+
+    /// Like `parse`, but also finds the byte range of the original input from
+    /// which each component of the name was parsed (see `NameSpans`), e.g.
+    /// for highlighting, or for writing corrections back to the source.
+    ///
+    /// # Examples
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// let input = "Dr. Juan (Juanito) de la Cruz, Jr.";
+    /// let (name, spans) = Name::parse_with_spans(input).unwrap();
+    /// assert_eq!("de la Cruz", name.surname());
+    /// assert_eq!("Juan", &input[spans.given_name().unwrap()]);
+    /// assert_eq!("Juanito", &input[spans.nickname().unwrap()]);
+    /// assert_eq!("Jr.", &input[spans.generational_suffix().unwrap()]);
+    ///
+    /// let surname = spans.surname();
+    /// assert_eq!("de la Cruz", &input[surname[0].start..surname[2].end]);
+    /// ```
+    pub fn parse_with_spans(name: &str) -> Option<(Name, NameSpans)> {
+        Name::parse_with_spans_with(name, &ParseOptions::default())
+    }
 
-        let name = normalize_nfkd_whitespace(name);
-        let name = nickname::strip_nickname(&name);
-        let parsed = parse::parse(&name)?;
+    /// Like `parse_with_spans`, but allows overriding our default heuristics
+    /// (see `ParseOptions`).
+    ///
+    /// # Examples
+    /// ```
+    /// use human_name::{Locale, Name, ParseOptions};
+    ///
+    /// let input = "Dato e Iradier, Eduardo";
+    /// let options = ParseOptions::new().locale(Locale::Es);
+    /// let (name, spans) = Name::parse_with_spans_with(input, &options).unwrap();
+    /// assert_eq!("Dato e Iradier", name.surname());
+    /// assert_eq!("Eduardo", &input[spans.given_name().unwrap()]);
+    /// ```
+    pub fn parse_with_spans_with(name: &str, options: &ParseOptions) -> Option<(Name, NameSpans)> {
+        preprocess(name, options, |input| {
+            let parsed = parse::parse_with(input.stripped, options, Default::default())?;
+            let result = input
+                .initialize_struct(&parsed)
+                .ok_or(ParseError::TooLong {
+                    len: input.stripped.len(),
+                })?;
+            let spans = NameSpans::new(
+                input.original,
+                input.normalized,
+                input.stripped,
+                &parsed,
+                input.bracketed,
+                result.parentheticals(),
+            );
+            Ok((result, spans))
+        })
+        .ok()
+    }
 
-        Name::initialize_struct(&parsed, name.len())
+    /// Lists the plausible readings of an ambiguous name, each with a score
+    /// from 0.0 (exclusive) to 1.0, from most to least likely.
+    ///
+    /// Alternatives include treating the first word as the surname, treating
+    /// more of the middle words as part of the surname, treating words which
+    /// look like titles as part of the name, and ignoring capitalization. The
+    /// score combines a prior for each kind of alternative with the `confidence`
+    /// of the resulting parse, and (like `confidence`) is only useful for
+    /// ranking or thresholding; it is not a calibrated probability.
+    ///
+    /// The result of `parse`, if any, is always among the candidates, though
+    /// not necessarily first if it relied on fallback heuristics.
+    ///
+    /// # Examples
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// let candidates = Name::parse_candidates("Lee Michael");
+    /// assert_eq!(2, candidates.len());
+    ///
+    /// let (first, first_score) = &candidates[0];
+    /// assert_eq!("Michael", first.surname());
+    ///
+    /// let (second, second_score) = &candidates[1];
+    /// assert_eq!("Lee", second.surname());
+    /// assert!(second_score < first_score);
+    ///
+    /// assert!(Name::parse_candidates("Cher").is_empty());
+    /// ```
+    pub fn parse_candidates(name: &str) -> Vec<(Name, f32)> {
+        Name::parse_candidates_with(name, &ParseOptions::default())
     }
 
-    fn initialize_struct(parsed: &parse::Name, name_len: usize) -> Option<Name> {
+    /// Like `parse_candidates`, but allows overriding our default heuristics
+    /// (see `ParseOptions`).
+    ///
+    /// # Examples
+    /// ```
+    /// use human_name::{Locale, Name, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().locale(Locale::Es);
+    /// let candidates = Name::parse_candidates_with("Dato e Iradier, Eduardo", &options);
+    /// assert_eq!("Dato e Iradier", candidates[0].0.surname());
+    ///
+    /// let options = ParseOptions::new().max_len(8);
+    /// assert!(Name::parse_candidates_with("Jane Doe", &options).is_empty());
+    /// ```
+    pub fn parse_candidates_with(name: &str, options: &ParseOptions) -> Vec<(Name, f32)> {
+        preprocess(name, options, |input| {
+            let mut candidates: Vec<(Name, f32)> = Vec::new();
+            for &(reading, prior) in parse::READINGS.iter() {
+                let mut parsed = match parse::parse_with(input.stripped, options, reading) {
+                    Ok(parsed) => parsed,
+                    Err(_) => continue,
+                };
+
+                // Don't pile alternate surname splits on top of surname-first order
+                let earliest_surname_index = if reading.surname_first {
+                    parsed.surname_index
+                } else {
+                    parsed.earliest_surname_index()
+                };
+
+                let mut prior = prior;
+                for surname_index in (earliest_surname_index..=parsed.surname_index).rev() {
+                    parsed.surname_index = surname_index;
+
+                    if let Some(candidate) = input.initialize_struct(&parsed) {
+                        let score = prior * candidate.confidence();
+                        match candidates
+                            .iter_mut()
+                            .find(|(c, _)| c.same_reading(&candidate))
+                        {
+                            Some((_, existing)) => {
+                                if score > *existing {
+                                    *existing = score;
+                                }
+                            }
+                            None => candidates.push((candidate, score)),
+                        }
+                    }
+
+                    prior *= parse::ALTERNATE_SURNAME_PRIOR;
+                }
+            }
+
+            // Stable, so ties go to the default reading
+            candidates.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+            Ok(candidates)
+        })
+        .unwrap_or_default()
+    }
+
+    fn same_reading(&self, other: &Name) -> bool {
+        self.text == other.text
+            && self.locations == other.locations
+            && self.given_name_words == other.given_name_words
+            && self.generation == other.generation
+            && self.honorific_prefix() == other.honorific_prefix()
+            && self.honorific_suffix() == other.honorific_suffix()
+    }
+
+    fn initialize_struct(
+        parsed: &parse::Name,
+        name_len: usize,
+        bracketed: &[nickname::Bracketed],
+        birth_surname: Option<&str>,
+        input: &str,
+    ) -> Option<Name> {
         let words = parsed.words();
         let surname_index = parsed.surname_index;
 
         let mut text = CompactString::with_capacity(name_len + surname_index);
         let mut initials = CompactString::with_capacity(surname_index);
 
-        let mut locations = SmallVec::with_capacity(words.len() + surname_index);
+        let mut locations: SmallVec<[Location; 6]> =
+            SmallVec::with_capacity(words.len() + surname_index);
         let mut locations_in_initials: SmallVec<[Location; 4]> =
             SmallVec::with_capacity(surname_index);
 
@@ -234,16 +493,54 @@ impl Name {
         debug_assert!(!initials.is_empty(), "Initials are empty!");
 
         let generation = parsed.generation;
-        let honorifics = {
-            let prefix = parsed
+        let flags = parsed.flags;
+        let extras = {
+            let honorific_prefix = parsed
                 .honorific_prefix()
                 .map(|s| s.into_owned().into_boxed_str());
-            let suffix = parsed
+            let honorific_suffix = parsed
                 .honorific_suffix()
                 .map(|s| s.into_owned().into_boxed_str());
-
-            if prefix.is_some() || suffix.is_some() {
-                Some(Box::new(Honorifics { prefix, suffix }))
+            let given_name = if locations_in_initials.is_empty() {
+                None
+            } else {
+                Some(&text[locations[0].range()])
+            };
+            let parentheticals = bracketed
+                .iter()
+                .map(|b| parenthetical::classify(b, given_name, input))
+                .collect::<Box<[_]>>();
+            let birth_surname = birth_surname
+                .or_else(|| {
+                    parentheticals
+                        .iter()
+                        .find(|p| p.is_birth_surname())
+                        .map(|p| p.text())
+                })
+                .map(Box::from);
+            let patronymic = parsed
+                .patronymic
+                .clone()
+                .map(|range| join_namecased(&words[range]));
+            let kunya = Some(parsed.kunya_words())
+                .filter(|words| !words.is_empty())
+                .map(join_namecased);
+
+            if honorific_prefix.is_some()
+                || honorific_suffix.is_some()
+                || birth_surname.is_some()
+                || patronymic.is_some()
+                || kunya.is_some()
+                || !parentheticals.is_empty()
+            {
+                Some(Box::new(Extras {
+                    honorific_prefix,
+                    honorific_suffix,
+                    birth_surname,
+                    patronymic,
+                    kunya,
+                    parentheticals,
+                }))
             } else {
                 None
             }
@@ -254,6 +551,11 @@ impl Name {
             .ok()?;
         let given_name_words = locations_in_initials.len().try_into().ok()?;
         let initials_len = initials.len().try_into().ok()?;
+        let second_surname_word = parsed
+            .second_surname_index
+            .map_or(0, |i| i - surname_index)
+            .try_into()
+            .ok()?;
 
         text.push_str(&initials);
         text.shrink_to_fit();
@@ -268,7 +570,10 @@ impl Name {
             surname_words,
             initials_len,
             generation,
-            honorifics,
+            second_surname_word,
+            surname_order: parsed.surname_order,
+            flags,
+            extras,
             surname_hash: Default::default(),
         })
     }
@@ -353,7 +658,7 @@ impl Name {
     /// let name = Name::parse("Able Baker Charlie Delta").unwrap();
     /// assert_eq!("Baker Charlie", name.middle_name().unwrap());
     /// ```
-    pub fn middle_name(&self) -> Option<Cow<str>> {
+    pub fn middle_name(&self) -> Option<Cow<'_, str>> {
         self.middle_name_iter().map(|i| i.join())
     }
 
@@ -403,6 +708,79 @@ impl Name {
         &self.text[start..end]
     }
 
+    /// Paternal surname, if the surname is a double surname in the Spanish or
+    /// Portuguese style (e.g. "Velasquez" in "Juan Velasquez y Garcia"). Each
+    /// part is one word plus any particles (e.g. "de la Vega Lopez").
+    ///
+    /// The paternal surname comes first, except in names parsed with
+    /// `Locale::Pt`. By default, only surnames joined by a conjunction are
+    /// split, but with `Locale::Es` or `Locale::Pt`, we also split surnames
+    /// with particles (e.g. "Iria del Río Gayo") and take the last two words
+    /// of a name for surnames (e.g. "Gabriel García Márquez").
+    ///
+    /// ```
+    /// use human_name::{Locale, Name, ParseOptions};
+    ///
+    /// let name = Name::parse("Juan Alberto Velasquez y Garcia").unwrap();
+    /// assert_eq!("Velasquez y Garcia", name.surname());
+    /// assert_eq!(Some("Velasquez"), name.paternal_surname());
+    /// assert_eq!(Some("Garcia"), name.maternal_surname());
+    ///
+    /// let options = ParseOptions::new().locale(Locale::Pt);
+    /// let name = Name::parse_with("Maria da Silva Santos", &options).unwrap();
+    /// assert_eq!(Some("Santos"), name.paternal_surname());
+    /// assert_eq!(Some("da Silva"), name.maternal_surname());
+    ///
+    /// let name = Name::parse("Jane Doe").unwrap();
+    /// assert_eq!(None, name.paternal_surname());
+    /// ```
+    pub fn paternal_surname(&self) -> Option<&str> {
+        self.paternal_surname_locations()
+            .map(|locations| self.text_spanning(locations))
+    }
+
+    /// Maternal surname, if the surname is a double surname in the Spanish or
+    /// Portuguese style (see `paternal_surname`)
+    ///
+    /// ```
+    /// use human_name::{Locale, Name, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().locale(Locale::Es);
+    /// let name = Name::parse_with("Iria del Rio Gayo", &options).unwrap();
+    /// assert_eq!(Some("Gayo"), name.maternal_surname());
+    /// ```
+    pub fn maternal_surname(&self) -> Option<&str> {
+        let (first, second) = self.double_surname_locations()?;
+        let maternal = if self.surname_order == SurnameOrder::MaternalFirst {
+            first
+        } else {
+            second
+        };
+        Some(self.text_spanning(maternal))
+    }
+
+    /// The surname by which a person is primarily known: the paternal surname
+    /// of a double surname (see `paternal_surname`), which comes last in
+    /// names parsed with `Locale::Pt`, or else the whole surname
+    ///
+    /// ```
+    /// use human_name::{Locale, Name, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().locale(Locale::Es);
+    /// let name = Name::parse_with("Gabriel García Márquez", &options).unwrap();
+    /// assert_eq!("Garci\u{301}a", name.primary_surname());
+    ///
+    /// let options = ParseOptions::new().locale(Locale::Pt);
+    /// let name = Name::parse_with("Gabriel García Márquez", &options).unwrap();
+    /// assert_eq!("Ma\u{301}rquez", name.primary_surname());
+    ///
+    /// let name = Name::parse("Jane Doe").unwrap();
+    /// assert_eq!("Doe", name.primary_surname());
+    /// ```
+    pub fn primary_surname(&self) -> &str {
+        self.paternal_surname().unwrap_or_else(|| self.surname())
+    }
+
     /// Generational suffix, if present
     ///
     /// ```
@@ -424,9 +802,9 @@ impl Name {
     /// assert_eq!(Some("Rev. Dr."), name.honorific_prefix());
     /// ```
     pub fn honorific_prefix(&self) -> Option<&str> {
-        self.honorifics
+        self.extras
             .as_ref()
-            .and_then(|h| h.prefix.as_ref())
+            .and_then(|e| e.honorific_prefix.as_ref())
             .map(|p| p.as_ref())
     }
 
@@ -439,12 +817,193 @@ impl Name {
     /// assert_eq!(Some("MD"), name.honorific_suffix());
     /// ```
     pub fn honorific_suffix(&self) -> Option<&str> {
-        self.honorifics
+        self.extras
             .as_ref()
-            .and_then(|h| h.suffix.as_ref())
+            .and_then(|e| e.honorific_suffix.as_ref())
             .map(|s| s.as_ref())
     }
 
+    /// Nickname, if present (quoted or parenthesized in the input)
+    ///
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// let name = Name::parse("Harrieta ('Harry') Keōpūolani Nāhiʻenaʻena").unwrap();
+    /// assert_eq!(Some("Harry"), name.nickname());
+    ///
+    /// let name = Name::parse("Jane 'JJ' Doe").unwrap();
+    /// assert_eq!(Some("JJ"), name.nickname());
+    /// assert_eq!("Jane Doe", name.display_full());
+    ///
+    /// let name = Name::parse("WILLIAM \"CHIP\" SMITH").unwrap();
+    /// assert_eq!(Some("Chip"), name.nickname());
+    /// ```
+    pub fn nickname(&self) -> Option<&str> {
+        self.parentheticals()
+            .iter()
+            .find(|p| p.kind() == ParentheticalKind::Nickname)
+            .map(|p| p.text())
+    }
+
+    /// Quoted or bracketed parts of the input, in order, classified by what
+    /// they seem to contain (see `ParentheticalKind`)
+    ///
+    /// ```
+    /// use human_name::{Name, ParentheticalKind};
+    ///
+    /// let name = Name::parse("John 'Jack' Doe (Acme Corp)").unwrap();
+    /// let kinds: Vec<_> = name.parentheticals().iter().map(|p| p.kind()).collect();
+    /// assert_eq!(vec![ParentheticalKind::Nickname, ParentheticalKind::Organization], kinds);
+    /// assert_eq!("Acme Corp", name.parentheticals()[1].text());
+    /// ```
+    pub fn parentheticals(&self) -> &[Parenthetical] {
+        self.extras
+            .as_ref()
+            .map(|e| &*e.parentheticals)
+            .unwrap_or(&[])
+    }
+
+    /// Surname at birth, if given after a marker like "née" or "geb.", with
+    /// or without brackets
+    ///
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// let name = Name::parse("Anna Mueller geb. Schmidt").unwrap();
+    /// assert_eq!("Mueller", name.surname());
+    /// assert_eq!(Some("Schmidt"), name.birth_surname());
+    ///
+    /// let name = Name::parse("Jane Doe (née Smith)").unwrap();
+    /// assert_eq!(Some("Smith"), name.birth_surname());
+    ///
+    /// let name = Name::parse("Max Born").unwrap();
+    /// assert_eq!("Born", name.surname());
+    /// assert_eq!(None, name.birth_surname());
+    /// ```
+    pub fn birth_surname(&self) -> Option<&str> {
+        self.extras
+            .as_ref()
+            .and_then(|e| e.birth_surname.as_ref())
+            .map(|s| s.as_ref())
+    }
+
+    /// Patronymic or matronymic, if we recognize one: among the middle names
+    /// (e.g. "Petrovich" in "Ivan Petrovich Sidorov"), after a particle like
+    /// "bin" or "ben", or in place of a surname (e.g. "Gudmundsdottir" in
+    /// "Bjork Gudmundsdottir", which is also the surname)
+    ///
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// let name = Name::parse("Ivan Petrovich Sidorov").unwrap();
+    /// assert_eq!(Some("Petrovich"), name.patronymic());
+    /// assert_eq!("Sidorov", name.surname());
+    ///
+    /// let name = Name::parse("Sidorov Ivan Petrovich").unwrap();
+    /// assert_eq!(Some("Ivan"), name.given_name());
+    /// assert_eq!(Some("Petrovich"), name.patronymic());
+    /// assert_eq!("Sidorov", name.surname());
+    ///
+    /// let name = Name::parse("Mohammed bin Salman Al Saud").unwrap();
+    /// assert_eq!(Some("bin Salman"), name.patronymic());
+    /// assert_eq!("Al Saud", name.surname());
+    ///
+    /// let name = Name::parse("Bjork Gudmundsdottir").unwrap();
+    /// assert_eq!(Some("Gudmundsdottir"), name.patronymic());
+    ///
+    /// let name = Name::parse("Roman Abramovich").unwrap();
+    /// assert_eq!(None, name.patronymic());
+    /// ```
+    pub fn patronymic(&self) -> Option<&str> {
+        self.extras
+            .as_ref()
+            .and_then(|e| e.patronymic.as_ref())
+            .map(|s| s.as_ref())
+    }
+
+    /// Kunya (e.g. "Abu Bakr" or "Umm Kulthum"), if recognized in an Arabic
+    /// name parsed with `Locale::Ar`. The kunya isn't part of the given name,
+    /// and isn't included in `display_full`.
+    ///
+    /// With `Locale::Ar`, the surname is the nisba or family name (e.g.
+    /// "al-Razi" or "Al Saud"), and the nasab or chain of patronymics (e.g.
+    /// "ibn Zakariya") is available as the `patronymic`.
+    ///
+    /// ```
+    /// use human_name::{Locale, Name, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().locale(Locale::Ar);
+    /// let name = Name::parse_with("Abu Bakr Muhammad ibn Zakariya al-Razi", &options).unwrap();
+    /// assert_eq!(Some("Abu Bakr"), name.kunya());
+    /// assert_eq!(Some("Muhammad"), name.given_name());
+    /// assert_eq!(Some("ibn Zakariya"), name.patronymic());
+    /// assert_eq!("al-Razi", name.surname());
+    /// assert_eq!("Muhammad ibn Zakariya al-Razi", name.display_full());
+    ///
+    /// // The kunya doesn't keep the name from matching the short form
+    /// assert!(name.consistent_with(&Name::parse("Muhammad al-Razi").unwrap()));
+    /// ```
+    pub fn kunya(&self) -> Option<&str> {
+        self.extras
+            .as_ref()
+            .and_then(|e| e.kunya.as_ref())
+            .map(|s| s.as_ref())
+    }
+
+    /// Former surnames, including the birth surname, e.g. from "Jane Doe
+    /// (née Smith)" or "Maria Lopez (Garcia)"
+    ///
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// let name = Name::parse("Maria Lopez (Garcia)").unwrap();
+    /// assert_eq!(vec!["Garcia"], name.former_surnames().collect::<Vec<_>>());
+    ///
+    /// let name = Name::parse("Mary Jones born Brown (formerly Smith)").unwrap();
+    /// assert_eq!(vec!["Brown", "Smith"], name.former_surnames().collect::<Vec<_>>());
+    /// ```
+    pub fn former_surnames(&self) -> impl Iterator<Item = &str> {
+        let birth_surname = self.birth_surname();
+        birth_surname.into_iter().chain(
+            self.parentheticals()
+                .iter()
+                .filter(|p| p.kind() == ParentheticalKind::FormerSurname)
+                .map(|p| p.text())
+                .filter(move |&text| Some(text) != birth_surname),
+        )
+    }
+
+    /// Which fallback heuristics the parser relied on to produce this name
+    ///
+    /// ```
+    /// use human_name::{Name, ParseFlags};
+    ///
+    /// let name = Name::parse("Jane Doe").unwrap();
+    /// assert!(name.parse_flags().is_empty());
+    ///
+    /// let name = Name::parse("John NG").unwrap();
+    /// assert!(name.parse_flags().contains(ParseFlags::IGNORED_CAPITALIZATION));
+    /// ```
+    pub fn parse_flags(&self) -> ParseFlags {
+        self.flags
+    }
+
+    /// How confident we are in the parse, from 0.0 (exclusive) to 1.0, based
+    /// on which fallback heuristics were needed (see `parse_flags`)
+    ///
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// let name = Name::parse("Jane Doe").unwrap();
+    /// assert_eq!(1.0, name.confidence());
+    ///
+    /// let name = Name::parse("John Smith Q").unwrap();
+    /// assert!(name.confidence() < 1.0);
+    /// ```
+    pub fn confidence(&self) -> f32 {
+        self.flags.confidence()
+    }
+
     /// First initial (with period) and surname.
     ///
     /// ```
@@ -459,7 +1018,7 @@ impl Name {
     /// let name = Name::parse("JOHN ALLEN Q DE LA MACDONALD JR").unwrap();
     /// assert_eq!("J. de la MacDonald", name.display_initial_surname());
     /// ```
-    pub fn display_initial_surname(&self) -> Cow<str> {
+    pub fn display_initial_surname(&self) -> Cow<'_, str> {
         if self.given_name_words == 0 && self.initials_len == 1 {
             Cow::Borrowed(&self.text[..self.surname_end_in_text()])
         } else {
@@ -485,7 +1044,7 @@ impl Name {
     /// let name = Name::parse("JOHN ALLEN Q DE LA MACDONALD JR").unwrap();
     /// assert_eq!("John de la MacDonald", name.display_first_last());
     /// ```
-    pub fn display_first_last(&self) -> Cow<str> {
+    pub fn display_first_last(&self) -> Cow<'_, str> {
         if self.given_name_words <= 1 && self.initials_len == 1 {
             Cow::Borrowed(&self.text[..self.surname_end_in_text()])
         } else if let Some(ref name) = self.given_name() {
@@ -537,7 +1096,7 @@ impl Name {
     /// assert_eq!("Harrieta Keōpūolani Nāhiʻenaʻena", name.display_full());
     /// ```
     #[inline]
-    pub fn display_full(&self) -> Cow<str> {
+    pub fn display_full(&self) -> Cow<'_, str> {
         let name = &self.text[..self.name_bytes()];
         if let Some(suffix) = self.generational_suffix() {
             let mut result = name.to_string();
@@ -562,19 +1121,21 @@ impl Name {
     /// let name = Name::parse("Air Chief Marshal Sir Harrieta ('Harry') Keōpūolani Nāhiʻenaʻena, GBE, KCB, ADC").unwrap();
     /// assert_eq!("Air Chief Marshal Sir Harrieta Keōpūolani Nāhiʻenaʻena GBE KCB ADC", name.display_full_with_honorifics());
     /// ```
-    pub fn display_full_with_honorifics(&self) -> Cow<str> {
-        if let Some(honorifics) = self.honorifics.as_ref() {
+    pub fn display_full_with_honorifics(&self) -> Cow<'_, str> {
+        let prefix = self.honorific_prefix();
+        let suffix = self.honorific_suffix();
+        if prefix.is_some() || suffix.is_some() {
             let mut result = String::with_capacity(
-                honorifics.prefix.as_ref().map(|t| t.len() + 1).unwrap_or(0)
+                prefix.map(|t| t.len() + 1).unwrap_or(0)
                     + self.byte_len()
-                    + honorifics.suffix.as_ref().map(|t| t.len() + 1).unwrap_or(0),
+                    + suffix.map(|t| t.len() + 1).unwrap_or(0),
             );
-            if let Some(prefix) = &honorifics.prefix {
+            if let Some(prefix) = prefix {
                 result.push_str(prefix);
                 result.push(' ');
             }
             result.push_str(&self.display_full());
-            if let Some(suffix) = &honorifics.suffix {
+            if let Some(suffix) = suffix {
                 result.push(' ');
                 result.push_str(suffix);
             }
@@ -623,7 +1184,7 @@ impl Name {
 
     fn hash_surname<H: Hasher>(&self, state: &mut H) {
         for c in self
-            .surname_iter()
+            .word_iter(self.comparison_surname_locations())
             .rev()
             .flat_map(|word| {
                 transliterate::to_ascii_casefolded_reversed(word)
@@ -646,15 +1207,14 @@ impl Name {
     #[inline]
     fn surname_iter(
         &self,
-    ) -> Words<impl Iterator<Item = Location> + DoubleEndedIterator + ExactSizeIterator + '_> {
+    ) -> Words<'_, impl DoubleEndedIterator<Item = Location> + ExactSizeIterator + '_> {
         self.word_iter(self.surname_locations())
     }
 
     #[inline]
     fn middle_name_iter(
         &self,
-    ) -> Option<Words<impl Iterator<Item = Location> + DoubleEndedIterator + ExactSizeIterator + '_>>
-    {
+    ) -> Option<Words<'_, impl DoubleEndedIterator<Item = Location> + ExactSizeIterator + '_>> {
         if self.given_name_words > 1 {
             Some(self.word_iter(&self.given_name_locations()[1..]))
         } else {
@@ -665,7 +1225,7 @@ impl Name {
     #[inline]
     fn given_iter(
         &self,
-    ) -> Words<impl Iterator<Item = Location> + DoubleEndedIterator + ExactSizeIterator + '_> {
+    ) -> Words<'_, impl DoubleEndedIterator<Item = Location> + ExactSizeIterator + '_> {
         self.word_iter(self.given_name_locations())
     }
 
@@ -673,8 +1233,7 @@ impl Name {
     fn word_iter<'a>(
         &'a self,
         locations: &'a [Location],
-    ) -> Words<'_, impl Iterator<Item = Location> + DoubleEndedIterator + ExactSizeIterator + '_>
-    {
+    ) -> Words<'a, impl DoubleEndedIterator<Item = Location> + ExactSizeIterator + 'a> {
         Words::new(&self.text, locations.iter().copied())
     }
 
@@ -689,12 +1248,123 @@ impl Name {
             [self.given_name_words.into()..(self.given_name_words + self.surname_words).into()]
     }
 
+    // The parts of a double surname, in order, leaving out any conjunction
+    // (e.g. "Velasquez" and "Garcia" in "Velasquez y Garcia")
+    fn double_surname_locations(&self) -> Option<(&[Location], &[Location])> {
+        let second = usize::from(self.second_surname_word);
+        if second == 0 {
+            return None;
+        }
+
+        let locations = self.surname_locations();
+        let before_second = &self.text[locations[second - 1].range()];
+        let first_end = if second > 1 && surname::is_conjunction(before_second, Locale::Es) {
+            second - 1
+        } else {
+            second
+        };
+        Some((&locations[..first_end], &locations[second..]))
+    }
+
+    fn paternal_surname_locations(&self) -> Option<&[Location]> {
+        self.double_surname_locations().map(|(first, second)| {
+            if self.surname_order == SurnameOrder::MaternalFirst {
+                second
+            } else {
+                first
+            }
+        })
+    }
+
+    // The part of the surname we compare and hash, which mustn't depend on
+    // the locale: the first part of a double surname joined by a conjunction
+    // (e.g. "Velasquez" in "Velasquez y Garcia"), or else all of it
+    fn comparison_surname_locations(&self) -> &[Location] {
+        let locations = self.surname_locations();
+        match self.double_surname_locations() {
+            Some((first, second)) if first.len() + second.len() < locations.len() => first,
+            _ => locations,
+        }
+    }
+
+    #[inline]
+    fn text_spanning(&self, locations: &[Location]) -> &str {
+        let start = locations[0].range().start;
+        let end = locations[locations.len() - 1].range().end;
+        &self.text[start..end]
+    }
+
     #[inline]
     fn given_names_in_initials(&self) -> &[Location] {
         &self.locations[(self.given_name_words + self.surname_words).into()..]
     }
 }
 
+// The input to the main parse, after the preparation shared by all our
+// parsing functions
+struct Preprocessed<'a> {
+    original: &'a str,
+    normalized: &'a str,
+    stripped: &'a str,
+    bracketed: &'a [nickname::Bracketed<'a>],
+    birth_surname: Option<&'a str>,
+}
+
+impl Preprocessed<'_> {
+    fn initialize_struct(&self, parsed: &parse::Name) -> Option<Name> {
+        Name::initialize_struct(
+            parsed,
+            self.stripped.len(),
+            self.bracketed,
+            self.birth_surname,
+            self.normalized,
+        )
+    }
+}
+
+// Checks the length limit, normalizes the input, and strips any nicknames and
+// birth surname, before passing the result to `f`
+fn preprocess<T>(
+    name: &str,
+    options: &ParseOptions,
+    f: impl FnOnce(&Preprocessed) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    if name.len() >= options.max_len {
+        return Err(ParseError::TooLong { len: name.len() });
+    }
+
+    let normalized = normalize_nfkd_whitespace(name);
+    let stripped = nickname::strip_nickname(&normalized);
+    let bracketed = if stripped.len() < normalized.len() {
+        nickname::find_bracketed(&normalized)
+    } else {
+        Vec::new()
+    };
+    let (stripped, birth_surname) = match parenthetical::split_birth_surname(&stripped) {
+        Some((rest, surname)) => (Cow::Owned(rest), Some(surname)),
+        None => (stripped, None),
+    };
+
+    f(&Preprocessed {
+        original: name,
+        normalized: &normalized,
+        stripped: &stripped,
+        bracketed: &bracketed,
+        birth_surname: birth_surname.as_deref(),
+    })
+}
+
+fn join_namecased(words: &[namepart::NamePart]) -> Box<str> {
+    let mut result = String::new();
+    for word in words {
+        if !result.is_empty() {
+            result.push(' ');
+        }
+        word.with_namecased(|s| result.push_str(s));
+    }
+    result.into_boxed_str()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -707,7 +1377,7 @@ mod tests {
     #[test]
     fn struct_size() {
         assert_eq!(80, std::mem::size_of::<Name>());
-        assert_eq!(32, std::mem::size_of::<Honorifics>());
+        assert_eq!(96, std::mem::size_of::<Extras>());
     }
 
     #[test]
@@ -727,6 +1397,111 @@ mod tests {
         });
     }
 
+    #[test]
+    fn try_parse_errors() {
+        let long = "Doe ".repeat(MAX_NAME_LEN / 4);
+        assert_eq!(
+            Some(ParseError::TooLong { len: long.len() }),
+            Name::try_parse(&long).err()
+        );
+        assert_eq!(Some(ParseError::NoWords), Name::try_parse("").err());
+        assert_eq!(
+            Some(ParseError::NoWords),
+            Name::try_parse("(Jane Doe)").err()
+        );
+        assert_eq!(
+            Some(ParseError::SingleWord {
+                word: "Doe".to_string()
+            }),
+            Name::try_parse("Mr. (John) Doe").err()
+        );
+        assert_eq!(
+            Some(ParseError::Implausible {
+                words: vec!["Esq.".to_string(), "Doe".to_string()]
+            }),
+            Name::try_parse("Doe, Esq.").err()
+        );
+    }
+
+    #[test]
+    fn parse_with_options() {
+        let options = ParseOptions::new().trust_capitalization(true);
+        assert_eq!(
+            "J. M. Doe",
+            Name::parse_with("JM Doe", &options).unwrap().display_full()
+        );
+        assert_eq!(
+            "J. A. N. E. Doe",
+            Name::parse_with("JANE DOE", &options)
+                .unwrap()
+                .display_full()
+        );
+
+        let options = ParseOptions::new().trust_capitalization(false);
+        assert_eq!(
+            "Al Doe",
+            Name::parse_with("AL Doe", &options).unwrap().display_full()
+        );
+
+        let options = ParseOptions::new().max_len(MAX_NAME_LEN * 2);
+        let long = format!("Jane{}Doe", " ".repeat(MAX_NAME_LEN));
+        assert!(Name::parse(&long).is_none());
+        assert!(Name::parse_with(&long, &options).is_some());
+
+        let options = ParseOptions::new().max_len(8);
+        assert_eq!(
+            Some(ParseError::TooLong { len: 8 }),
+            Name::try_parse_with("Jane Doe", &options).err()
+        );
+        assert!(Name::parse_with("J. Doe", &options).is_some());
+    }
+
+    #[test]
+    fn parse_candidates() {
+        let surnames = |input| {
+            Name::parse_candidates(input)
+                .into_iter()
+                .map(|(n, _)| n.surname().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec!["Doe"], surnames("J. Doe"));
+        assert_eq!(vec!["de la Vega"], surnames("Juan de la Vega"));
+        assert_eq!(vec!["Michael", "Lee"], surnames("Lee Michael"));
+        assert_eq!(vec!["Ma", "John"], surnames("JOHN MA"));
+        assert_eq!(
+            vec!["Smith", "Smith", "John Smith"],
+            surnames("Smith, Dr. John")
+        );
+        assert_eq!(
+            vec!["Lopez", "Juan", "Garcia Lopez"],
+            surnames("Juan Garcia Lopez")
+        );
+        assert_eq!(
+            vec!["Smith", "John", "PhD", "Smith PhD"],
+            surnames("John Smith PhD")
+        );
+        assert!(surnames("Cher").is_empty());
+
+        // Keeping titles as part of the name
+        let candidates = Name::parse_candidates("Dr. John Smith MD");
+        let (name, _) = &candidates[2];
+        assert_eq!("MD", name.surname());
+        assert_eq!(Some("Dr"), name.given_name());
+        assert_eq!("Dr John Smith MD", name.display_full());
+
+        let candidates = Name::parse_candidates("Smith, Dr. John");
+        let (name, _) = &candidates[1];
+        assert_eq!("Smith", name.surname());
+        assert_eq!(Some("John"), name.middle_name().as_deref());
+
+        let candidates = Name::parse_candidates("Neto John SMITH");
+        let (name, score) = &candidates[0];
+        assert_eq!("Smith", name.surname());
+        assert_eq!(Some("John"), name.middle_name().as_deref());
+        assert_eq!(0.8, *score);
+    }
+
     #[test]
     fn parse_high_proportion_of_combining_chars() {
         let name = Name::parse(".ΰ\u{330}\u{610}`");
@@ -859,7 +1634,7 @@ mod tests {
         let parsed = parse::parse(&*name).unwrap();
         b.iter(|| {
             black_box(
-                Name::initialize_struct(&parsed, name.len())
+                Name::initialize_struct(&parsed, name.len(), &[], None, name)
                     .unwrap()
                     .byte_len(),
             )
@@ -873,7 +1648,7 @@ mod tests {
         let parsed = parse::parse(&*name).unwrap();
         b.iter(|| {
             black_box(
-                Name::initialize_struct(&parsed, name.len())
+                Name::initialize_struct(&parsed, name.len(), &[], None, name)
                     .unwrap()
                     .byte_len(),
             )
@@ -887,7 +1662,7 @@ mod tests {
         let parsed = parse::parse(&*name).unwrap();
         b.iter(|| {
             black_box(
-                Name::initialize_struct(&parsed, name.len())
+                Name::initialize_struct(&parsed, name.len(), &[], None, name)
                     .unwrap()
                     .byte_len(),
             )
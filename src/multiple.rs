@@ -0,0 +1,143 @@
+use super::{Name, ParseError};
+
+pub const CONJUNCTIONS: [&str; 3] = ["&", "and", "+"];
+
+const COLLECTIVES: [&str; 3] = ["family", "families", "household"];
+
+/// Parses a string which may represent several people, such as a joint
+/// addressee on a mailing list, into a `Name` for each person.
+///
+/// Splits the input on "&", "and", "+", and ";". Where one part is missing
+/// a surname (as in "John and Jane Doe"), borrows the surname of the next
+/// part (or, failing that, the previous part). Where one part consists only
+/// of titles (as in "Mr. & Mrs. John Doe"), applies those titles to the
+/// next part's name.
+///
+/// Collective names like "The Doe Family" don't identify any individual
+/// (and `Name` requires at least a first initial), so they yield no names.
+///
+/// # Examples
+/// ```
+/// use human_name::parse_all;
+///
+/// let names = parse_all("John and Jane Doe");
+/// assert_eq!(2, names.len());
+/// assert_eq!("John Doe", names[0].display_full());
+/// assert_eq!("Jane Doe", names[1].display_full());
+///
+/// let names = parse_all("Mr. & Mrs. John Doe");
+/// assert_eq!(2, names.len());
+/// assert_eq!("Mr. John Doe", names[0].display_full_with_honorifics());
+/// assert_eq!("Mrs. John Doe", names[1].display_full_with_honorifics());
+///
+/// let names = parse_all("Jane Doe & John Smith");
+/// assert_eq!("Doe", names[0].surname());
+/// assert_eq!("Smith", names[1].surname());
+///
+/// assert!(parse_all("The Doe Family").is_empty());
+/// ```
+pub fn parse_all(input: &str) -> Vec<Name> {
+    let parts: Vec<String> = split_people(input)
+        .into_iter()
+        .filter(|part| !is_collective(part))
+        .collect();
+
+    let results: Vec<Result<Name, ParseError>> =
+        parts.iter().map(|part| Name::try_parse(part)).collect();
+
+    // Parts which don't parse alone borrow from the next (or previous) part
+    // which does, so find those before moving any names out
+    let fallbacks: Vec<Option<Name>> = results
+        .iter()
+        .enumerate()
+        .map(|(i, result)| {
+            let error = result.as_ref().err()?;
+            let donor = results[i + 1..]
+                .iter()
+                .chain(results[..i].iter().rev())
+                .find_map(|r| r.as_ref().ok())?;
+
+            let combined = match error {
+                ParseError::OnlyTitles { .. } => format!("{} {}", parts[i], donor.display_full()),
+                _ => format!("{} {}", parts[i], donor.surname()),
+            };
+            Name::try_parse(&combined).ok()
+        })
+        .collect();
+
+    results
+        .into_iter()
+        .zip(fallbacks)
+        .filter_map(|(result, fallback)| result.ok().or(fallback))
+        .collect()
+}
+
+fn split_people(input: &str) -> Vec<String> {
+    let mut parts = vec![String::new()];
+
+    let spaced = input.replace('&', " & ").replace(';', " ; ");
+    for word in spaced.split_whitespace() {
+        if word == ";" || CONJUNCTIONS.iter().any(|c| c.eq_ignore_ascii_case(word)) {
+            if !parts.last().unwrap().is_empty() {
+                parts.push(String::new());
+            }
+        } else {
+            let part = parts.last_mut().unwrap();
+            if !part.is_empty() {
+                part.push(' ');
+            }
+            part.push_str(word);
+        }
+    }
+
+    if parts.len() > 1 && parts.last().unwrap().is_empty() {
+        parts.pop();
+    }
+    parts
+}
+
+fn is_collective(part: &str) -> bool {
+    part.split_whitespace()
+        .last()
+        .map(|word| COLLECTIVES.iter().any(|c| c.eq_ignore_ascii_case(word)))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split() {
+        assert_eq!(vec!["Jane Doe"], split_people("Jane Doe"));
+        assert_eq!(vec!["John", "Jane Doe"], split_people("John and Jane Doe"));
+        assert_eq!(
+            vec!["Mr.", "Mrs. John Doe"],
+            split_people("Mr.&Mrs. John Doe")
+        );
+        assert_eq!(
+            vec!["Jane Doe", "John Smith", "Al Jones"],
+            split_people("Jane Doe; John Smith & Al Jones")
+        );
+        assert_eq!(vec!["Anderson Cooper"], split_people("Anderson Cooper"));
+    }
+
+    #[test]
+    fn shared_surnames() {
+        let names = parse_all("Doe, John and Jane");
+        assert_eq!(2, names.len());
+        assert_eq!("John Doe", names[0].display_full());
+        assert_eq!("Jane Doe", names[1].display_full());
+
+        let names = parse_all("Dr. John and Jane Q. Doe");
+        assert_eq!("Dr. John Doe", names[0].display_full_with_honorifics());
+        assert_eq!("Jane Q. Doe", names[1].display_full());
+    }
+
+    #[test]
+    fn collectives() {
+        assert!(parse_all("The Doe Family").is_empty());
+        assert!(parse_all("Smith Household").is_empty());
+        assert_eq!(1, parse_all("Jane Familyson").len());
+    }
+}
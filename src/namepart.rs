@@ -74,7 +74,7 @@ impl<'a> NamePart<'a> {
         }
     }
 
-    pub fn from_word(word: &str, trust_capitalization: bool, location: Location) -> NamePart {
+    pub fn from_word(word: &str, trust_capitalization: bool, location: Location) -> NamePart<'_> {
         NamePart::from_word_and_counts(word, categorize_chars(word), trust_capitalization, location)
     }
 
@@ -84,7 +84,7 @@ impl<'a> NamePart<'a> {
         counts: CharacterCounts,
         trust_capitalization: bool,
         location: Location,
-    ) -> NamePart {
+    ) -> NamePart<'_> {
         let CharacterCounts {
             chars,
             alpha,
@@ -159,6 +159,32 @@ impl<'a> NamePart<'a> {
         }
     }
 
+    // For a word we already have reason to think is part of a surname (e.g.
+    // "LE" in "Jean-Marie LE PEN"), ignore capitalization and length cues that
+    // would otherwise make it look like initials
+    pub fn surname_from_word_and_counts(
+        word: &str,
+        counts: CharacterCounts,
+        location: Location,
+    ) -> NamePart<'_> {
+        let only_letters = counts.chars == counts.alpha && counts.chars > 1;
+        let ascii = counts.chars == counts.ascii_alpha;
+
+        let part = NamePart::from_word_and_counts(word, counts, false, location);
+        if part.is_initials() && only_letters {
+            NamePart {
+                category: Category::Name(Cow::Owned(namecase::namecase(
+                    word,
+                    ascii,
+                    location == Location::Middle,
+                ))),
+                ..part
+            }
+        } else {
+            part
+        }
+    }
+
     #[inline]
     pub fn is_initials(&self) -> bool {
         self.category == Category::Initials
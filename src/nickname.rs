@@ -1,3 +1,5 @@
+use super::cjk;
+use super::options::Locale;
 use super::transliterate;
 use crate::case::*;
 use crate::features::starts_with_consonant;
@@ -11,6 +13,37 @@ static NAMES_BY_NICK_PREFIX: phf::Map<&'static str, &'static [&'static str]> =
 static NAMES_BY_IRREGULAR_NICK: phf::Map<&'static str, &'static [&'static str]> =
     include!(concat!(env!("OUT_DIR"), "/names_by_irregular_nick.rs"));
 
+static GIVEN_NAMES: phf::Set<&'static str> = include!(concat!(env!("OUT_DIR"), "/given_names.rs"));
+
+// Nicknames particular to one language (e.g. "Pepe" for "José" in Spanish),
+// each compiled in only with its cargo feature
+#[cfg(feature = "nicknames-de")]
+static NAMES_BY_NICK_DE: phf::Map<&'static str, &'static [&'static str]> =
+    include!(concat!(env!("OUT_DIR"), "/names_by_nick_de.rs"));
+#[cfg(feature = "nicknames-es")]
+static NAMES_BY_NICK_ES: phf::Map<&'static str, &'static [&'static str]> =
+    include!(concat!(env!("OUT_DIR"), "/names_by_nick_es.rs"));
+#[cfg(feature = "nicknames-it")]
+static NAMES_BY_NICK_IT: phf::Map<&'static str, &'static [&'static str]> =
+    include!(concat!(env!("OUT_DIR"), "/names_by_nick_it.rs"));
+#[cfg(feature = "nicknames-ru")]
+static NAMES_BY_NICK_RU: phf::Map<&'static str, &'static [&'static str]> =
+    include!(concat!(env!("OUT_DIR"), "/names_by_nick_ru.rs"));
+
+// Each spelling of a given name which has several (e.g. "Mohamed" or
+// "Mehmet"), as lowercase letters only, mapped to the first spelling listed
+static SPELLING_VARIANTS: phf::Map<&'static str, &'static str> =
+    include!(concat!(env!("OUT_DIR"), "/spelling_variants.rs"));
+
+// Each language's form of a given name (e.g. "Giovanni"), folded like
+// `SPELLING_VARIANTS`, mapped to the first form listed for each name it may
+// be a form of (e.g. "john")
+static EQUIVALENT_NAMES: phf::Map<&'static str, &'static [&'static str]> =
+    include!(concat!(env!("OUT_DIR"), "/equivalent_names.rs"));
+
+// Longer than any spelling in `SPELLING_VARIANTS` or `EQUIVALENT_NAMES`
+const MAX_SPELLING_LEN: usize = 16;
+
 const DIMINUTIVE_EXCEPTIONS: [&str; 6] = ["Mary", "Joy", "Roy", "Guy", "Amy", "Troy"];
 
 const FINAL_SYLLABLES_EXCEPTIONS: [&str; 1] = [
@@ -87,7 +120,7 @@ fn find_nick_open(input: &str) -> Option<NickOpen> {
 }
 
 #[cold]
-fn find_close_and_strip(input: &str, open: NickOpen) -> Cow<str> {
+fn find_close_and_strip(input: &str, open: NickOpen) -> Cow<'_, str> {
     let NickOpen {
         start_index,
         open_char,
@@ -136,7 +169,7 @@ fn find_close_and_strip(input: &str, open: NickOpen) -> Cow<str> {
 
 // Optimized for the case where there is no nickname, and secondarily for the
 // case where there is only one. Two or more probably means bad input.
-pub fn strip_nickname(input: &str) -> Cow<str> {
+pub fn strip_nickname(input: &str) -> Cow<'_, str> {
     if let Some(open) = find_nick_open(input) {
         find_close_and_strip(input, open)
     } else {
@@ -144,6 +177,59 @@ pub fn strip_nickname(input: &str) -> Cow<str> {
     }
 }
 
+// A span of input that `strip_nickname` would remove
+#[derive(Debug, PartialEq, Eq)]
+pub struct Bracketed<'a> {
+    pub text: &'a str,
+    pub quoted: bool,
+    pub trailing: bool,
+}
+
+// Finds the spans that `strip_nickname` would remove, following the same
+// rules for matching open and close characters.
+pub fn find_bracketed(input: &str) -> Vec<Bracketed<'_>> {
+    let mut result = Vec::new();
+    let mut search_from = 0;
+
+    while let Some(open) = find_nick_open(&input[search_from..]) {
+        let start = search_from + open.start_index + open.open_char.len_utf8();
+        let (text, next) = match input[start..].find(open.expect_close_char) {
+            Some(found_at) => {
+                let i = start + found_at;
+                let j = i + open.expect_close_char.len_utf8();
+                if !open.expect_closing_space || j >= input.len() || input[j..].starts_with(' ') {
+                    (Some(&input[start..i]), j)
+                } else {
+                    (None, i)
+                }
+            }
+            None if !open.expect_closing_space => (Some(&input[start..]), input.len()),
+            None => (None, start),
+        };
+
+        if let Some(text) = text.map(trim_bracketed).filter(|text| !text.is_empty()) {
+            let rest = input[next..].trim_start();
+            result.push(Bracketed {
+                text,
+                quoted: open.expect_closing_space,
+                trailing: rest.is_empty() || rest.starts_with(','),
+            });
+        }
+
+        if next >= input.len() {
+            break;
+        }
+        search_from = next;
+    }
+
+    result
+}
+
+// Drops whitespace and any quotes nested inside parens, as in `("Bill")`
+fn trim_bracketed(text: &str) -> &str {
+    text.trim_matches(|c: char| c.is_whitespace() || "'\"‘’“”".contains(c))
+}
+
 struct NameVariants<'a> {
     original: &'a str,
     direct_variants: Option<&'a [&'static str]>,
@@ -167,7 +253,7 @@ impl<'a> NameVariants<'a> {
         }
     }
 
-    pub fn iter_with_original(&self) -> NameVariantIter {
+    pub fn iter_with_original(&self) -> NameVariantIter<'_> {
         NameVariantIter {
             original: iter::once(self.original),
             direct_variants: self.direct_variants.map(|names| names.iter()),
@@ -223,7 +309,7 @@ impl<'a> Iterator for NameVariantIter<'a> {
 
 impl<'a> ExactSizeIterator for NameVariantIter<'a> {}
 
-fn transliterate_if_non_ascii(s: &str) -> Cow<str> {
+fn transliterate_if_non_ascii(s: &str) -> Cow<'_, str> {
     if s.is_ascii() && s.bytes().all(|b| b.is_ascii_alphabetic()) {
         // We were already titlecased by namecase::namecase,
         // so we don't need to do anything
@@ -235,9 +321,38 @@ fn transliterate_if_non_ascii(s: &str) -> Cow<str> {
     }
 }
 
+// Whether the word is a given name (or nickname) from our nickname data,
+// ignoring accents (e.g. "José")
+pub fn is_known_given_name(word: &str) -> bool {
+    let word = if word.is_ascii() {
+        Cow::Borrowed(word)
+    } else {
+        transliterate_if_non_ascii(word)
+    };
+    !word.is_empty()
+        && word.bytes().all(|b| b.is_ascii_alphabetic())
+        && GIVEN_NAMES.contains(capitalize_word(&word, true).as_str())
+}
+
 pub fn have_matching_variants(original_a: &str, original_b: &str) -> bool {
+    // Given names in CJK script are a single unit, without nicknames, so we
+    // only accept the same name or its romanization (e.g. "泽东" and "Zedong")
+    let cjk = cjk::has_cjk(original_a) || cjk::has_cjk(original_b);
+
     let original_a = transliterate_if_non_ascii(original_a);
     let original_b = transliterate_if_non_ascii(original_b);
+    if cjk {
+        return original_a.eq_ignore_ascii_case(&original_b);
+    }
+
+    if let (Some(a), Some(b)) = (
+        standard_spelling(&original_a),
+        standard_spelling(&original_b),
+    ) {
+        if a == b {
+            return true;
+        }
+    }
 
     let a_variants = NameVariants::for_name(&original_a);
     let b_variants = NameVariants::for_name(&original_b);
@@ -249,6 +364,82 @@ pub fn have_matching_variants(original_a: &str, original_b: &str) -> bool {
     })
 }
 
+// Whether the names are known spellings of the same name, such as
+// transliterations of "Muhammad" (e.g. "Mohamed" or "Mehmet")
+pub fn are_spelling_variants(a: &str, b: &str) -> bool {
+    match (standard_spelling(a), standard_spelling(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+// Every locale with nicknames particular to its language
+const NICKNAME_LOCALES: [Locale; 4] = [Locale::De, Locale::Es, Locale::It, Locale::Ru];
+
+// The nicknames particular to the locale's language, if we have them
+#[allow(unused_variables)]
+fn names_by_nick(
+    locale: Locale,
+) -> Option<&'static phf::Map<&'static str, &'static [&'static str]>> {
+    match locale {
+        #[cfg(feature = "nicknames-de")]
+        Locale::De => Some(&NAMES_BY_NICK_DE),
+        #[cfg(feature = "nicknames-es")]
+        Locale::Es => Some(&NAMES_BY_NICK_ES),
+        #[cfg(feature = "nicknames-it")]
+        Locale::It => Some(&NAMES_BY_NICK_IT),
+        #[cfg(feature = "nicknames-ru")]
+        Locale::Ru => Some(&NAMES_BY_NICK_RU),
+        _ => None,
+    }
+}
+
+// Whether one name is a nickname for the other in any of the locales'
+// languages (or any language we have nicknames for, given `Locale::Any`)
+pub fn is_locale_nickname(a: &str, b: &str, locales: &[Locale]) -> bool {
+    let a = transliterate_if_non_ascii(a);
+    let b = transliterate_if_non_ascii(b);
+    let is_nickname_for = |nick: &str, name: &str, nicks: &phf::Map<&str, &[&str]>| {
+        nicks
+            .get(capitalize_word(nick, true).as_str())
+            .map_or(false, |names| {
+                names.iter().any(|n| n.eq_ignore_ascii_case(name))
+            })
+    };
+
+    locales
+        .iter()
+        .flat_map(|locale| match locale {
+            Locale::Any => &NICKNAME_LOCALES[..],
+            _ => std::slice::from_ref(locale),
+        })
+        .filter_map(|&locale| names_by_nick(locale))
+        .any(|nicks| is_nickname_for(&a, &b, nicks) || is_nickname_for(&b, &a, nicks))
+}
+
+// Whether the names are forms of the same name in different languages
+// (e.g. "John", "Juan", "Johann", "Giovanni" and "Ivan")
+pub fn are_equivalent_names(a: &str, b: &str) -> bool {
+    let equivalents = |name: &str| {
+        let mut buffer = [0; MAX_SPELLING_LEN];
+        transliterate::to_ascii_casefolded_in(name, &mut buffer)
+            .and_then(|folded| EQUIVALENT_NAMES.get(folded).copied())
+    };
+
+    match (equivalents(a), equivalents(b)) {
+        (Some(mine), Some(theirs)) => mine.iter().any(|name| theirs.contains(name)),
+        _ => false,
+    }
+}
+
+// The spelling standing in for all known spellings of the same name (e.g.
+// "muhammad" for "Mohammed"), ignoring case and any spaces or hyphens
+fn standard_spelling(name: &str) -> Option<&'static str> {
+    let mut buffer = [0; MAX_SPELLING_LEN];
+    let folded = transliterate::to_ascii_casefolded_in(name, &mut buffer)?;
+    SPELLING_VARIANTS.get(folded).copied()
+}
+
 #[inline]
 fn variants_match(a: &str, b: &str) -> bool {
     let (longer, shorter) = if a.len() >= b.len() { (a, b) } else { (b, a) };
@@ -422,6 +613,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn spelling_variants() {
+        assert!(have_matching_variants("Mohamed", "Muhammad"));
+        assert!(have_matching_variants("Mehmet", "Mohammed"));
+        assert!(have_matching_variants("Hossein", "Husain"));
+        assert!(have_matching_variants("Abd al-Rahman", "Abdulrahman"));
+        assert!(are_spelling_variants("YOUSSEF", "Yusuf"));
+        assert!(!are_spelling_variants("Hussein", "Hassan"));
+        assert!(!are_spelling_variants("John", "John"));
+    }
+
+    #[test]
+    fn equivalent_names() {
+        assert!(are_equivalent_names("John", "Giovanni"));
+        assert!(are_equivalent_names("Johann", "Ivan"));
+        assert!(are_equivalent_names("Jacques", "James"));
+        assert!(are_equivalent_names("Jacques", "Jakob"));
+        assert!(are_equivalent_names("Jose\u{301}", "Giuseppe"));
+        assert!(!are_equivalent_names("James", "Jakob"));
+        assert!(!are_equivalent_names("John", "Joseph"));
+        assert!(!have_matching_variants("John", "Giovanni"));
+    }
+
+    #[cfg(feature = "nicknames-de")]
+    #[test]
+    fn german_nicknames() {
+        assert!(is_locale_nickname("Sepp", "Josef", &[Locale::De]));
+        assert!(is_locale_nickname("Friedrich", "Fritz", &[Locale::De]));
+        assert!(!is_locale_nickname("Sepp", "Josef", &[Locale::It]));
+    }
+
+    #[cfg(feature = "nicknames-es")]
+    #[test]
+    fn spanish_nicknames() {
+        assert!(is_locale_nickname("Pepe", "Jose\u{301}", &[Locale::Es]));
+        assert!(is_locale_nickname("Francisco", "Paco", &[Locale::Es]));
+        assert!(is_locale_nickname("PACO", "Francisco", &[Locale::Any]));
+        assert!(!is_locale_nickname("Paco", "Jose", &[Locale::Es]));
+        assert!(!is_locale_nickname("Pepe", "Jose", &[Locale::En]));
+        assert!(!have_matching_variants("Pepe", "Jose"));
+    }
+
+    #[cfg(feature = "nicknames-it")]
+    #[test]
+    fn italian_nicknames() {
+        assert!(is_locale_nickname("Beppe", "Giuseppe", &[Locale::It]));
+        assert!(is_locale_nickname(
+            "Gigi",
+            "Luigi",
+            &[Locale::De, Locale::It]
+        ));
+    }
+
+    #[cfg(feature = "nicknames-ru")]
+    #[test]
+    fn russian_nicknames() {
+        assert!(is_locale_nickname("Sasha", "Aleksandr", &[Locale::Ru]));
+        assert!(is_locale_nickname("Dmitry", "Dima", &[Locale::Ru]));
+        assert!(!is_locale_nickname("Sasha", "Mikhail", &[Locale::Ru]));
+    }
+
     #[test]
     fn strip_nothing() {
         assert_eq!("Robert Roberts", strip_nickname("Robert Roberts"));
@@ -450,6 +702,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn find_nothing() {
+        assert!(find_bracketed("Robert Roberts").is_empty());
+        assert!(find_bracketed("Ro'bert R'oberts").is_empty());
+        assert!(find_bracketed("Robert Mr. Bob' Roberts").is_empty());
+        assert!(find_bracketed("Robert () Roberts").is_empty());
+    }
+
+    #[test]
+    fn find_spans() {
+        let texts = |input| {
+            find_bracketed(input)
+                .into_iter()
+                .map(|b| b.text)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec!["Mr. Bob"], texts("Robert (Mr. Bob) Roberts"));
+        assert_eq!(vec!["Mr. Bob"], texts("Robert (Mr. Bob"));
+        assert_eq!(vec!["Mr. Bob"], texts("Robert 'Mr. Bob' Roberts"));
+        assert_eq!(vec!["Bob"], texts("Robert (\"Bob\") Roberts"));
+        assert_eq!(vec!["Bob"], texts("Ro'bert 'Bob' Roberts"));
+        assert_eq!(vec!["Bob", "Acme"], texts("Robert 'Bob' Roberts (Acme)"));
+    }
+
+    #[test]
+    fn find_span_positions() {
+        assert_eq!(
+            vec![Bracketed {
+                text: "Bob",
+                quoted: true,
+                trailing: false
+            }],
+            find_bracketed("Robert 'Bob' Roberts")
+        );
+        assert_eq!(
+            vec![Bracketed {
+                text: "Acme",
+                quoted: false,
+                trailing: true
+            }],
+            find_bracketed("Robert Roberts (Acme), PhD")
+        );
+    }
+
     #[test]
     fn unspaced_quotes() {
         assert_eq!("Ro'bert R'oberts", strip_nickname("Ro'bert R'oberts"));
@@ -0,0 +1,348 @@
+use super::MAX_NAME_LEN;
+
+/// A set of conventions for interpreting names from a particular language
+/// or region.
+///
+/// Currently, this determines which particles (e.g. "van" or "de la") are
+/// taken to begin a surname, and which conjunctions (e.g. the "y" in
+/// "Romero y Galdámez") are taken to join parts of a compound surname. For
+/// Arabic, it also enables parsing the structure of Arabic names (see
+/// `Name::kunya`). For Hungarian and Vietnamese, names which start with a
+/// common family name (e.g. "Kovács János" or "Nguyễn Văn Thiệu") are taken
+/// to be in surname-first order, even with `NameOrder::GivenFirst`. For
+/// Chinese, Japanese, and Korean, names written in those scripts are taken to
+/// be in surname-first order, and split after a known family name if written
+/// without a space (e.g. "欧阳修", "山田太郎", or "남궁민").
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Locale {
+    /// Recognize particles and conjunctions from any of the languages we know
+    /// about (the default)
+    Any,
+    /// English
+    En,
+    /// Spanish
+    Es,
+    /// Portuguese
+    Pt,
+    /// French
+    Fr,
+    /// Italian
+    It,
+    /// Dutch
+    Nl,
+    /// German
+    De,
+    /// Russian
+    Ru,
+    /// Hungarian
+    Hu,
+    /// Vietnamese
+    Vi,
+    /// Arabic
+    Ar,
+    /// Chinese
+    Zh,
+    /// Japanese
+    Ja,
+    /// Korean
+    Ko,
+}
+
+impl Default for Locale {
+    fn default() -> Locale {
+        Locale::Any
+    }
+}
+
+/// The order in which given names and surnames appear in input without a
+/// comma. (Input with a comma, like "Doe, Jane", is always taken to start with
+/// the surname.)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NameOrder {
+    /// Given name first, e.g. "Jane Doe" (the default)
+    GivenFirst,
+    /// Surname first, e.g. "Kim Il-sung" or "DOE JANE A"
+    SurnameFirst,
+}
+
+impl Default for NameOrder {
+    fn default() -> NameOrder {
+        NameOrder::GivenFirst
+    }
+}
+
+/// Options controlling how `Name::parse_with` interprets its input.
+///
+/// Constructed with `ParseOptions::new()` (or `default()`), followed by any
+/// of the builder methods below.
+///
+/// ```
+/// use human_name::{Locale, Name, ParseOptions};
+///
+/// let name = Name::parse("Nguyen Van Thieu").unwrap();
+/// assert_eq!("Van Thieu", name.surname());
+///
+/// let options = ParseOptions::new().locale(Locale::Vi);
+/// let name = Name::parse_with("Nguyen Van Thieu", &options).unwrap();
+/// assert_eq!("Nguyen", name.surname());
+/// assert_eq!(Some("Thieu"), name.given_name());
+/// assert_eq!("Thieu Van Nguyen", name.display_full());
+///
+/// let options = ParseOptions::new().locale(Locale::Hu);
+/// let name = Name::parse_with("Kovacs Janos", &options).unwrap();
+/// assert_eq!("Kovacs", name.surname());
+/// assert_eq!(Some("Janos"), name.given_name());
+///
+/// let options = ParseOptions::new().locale(Locale::Zh);
+/// let name = Name::parse_with("欧阳修", &options).unwrap();
+/// assert_eq!("欧阳", name.surname());
+/// assert_eq!(Some("修"), name.given_name());
+///
+/// let name = Name::parse_with("毛泽东", &options).unwrap();
+/// assert_eq!(Some("泽东"), name.given_name());
+/// assert_eq!("泽", name.initials());
+/// assert!(name.consistent_with(&Name::parse("Zedong Mao").unwrap()));
+///
+/// let options = ParseOptions::new().trust_capitalization(false);
+/// let name = Name::parse_with("AL Doe", &options).unwrap();
+/// assert_eq!(Some("Al"), name.given_name());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub(crate) trust_capitalization: Option<bool>,
+    pub(crate) locale: Locale,
+    pub(crate) name_order: NameOrder,
+    pub(crate) initials_after_surname: bool,
+    pub(crate) max_len: usize,
+}
+
+impl Default for ParseOptions {
+    fn default() -> ParseOptions {
+        ParseOptions {
+            trust_capitalization: None,
+            locale: Locale::default(),
+            name_order: NameOrder::default(),
+            initials_after_surname: false,
+            max_len: MAX_NAME_LEN,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// The default options, equivalent to those used by `Name::parse`.
+    pub fn new() -> ParseOptions {
+        ParseOptions::default()
+    }
+
+    /// Whether to take capitalization into account (e.g., to parse "AL Doe"
+    /// as "A. L. Doe", but "Al Doe" as a given name and surname).
+    ///
+    /// By default, we trust capitalization only if the input is mixed-case.
+    pub fn trust_capitalization(mut self, trust: bool) -> ParseOptions {
+        self.trust_capitalization = Some(trust);
+        self
+    }
+
+    /// Which locale's particles and conjunctions to recognize when looking
+    /// for the start of the surname, along with any other conventions of the
+    /// locale (see `Locale`).
+    pub fn locale(mut self, locale: Locale) -> ParseOptions {
+        self.locale = locale;
+        self
+    }
+
+    /// Whether to expect the given name or the surname first, when there is
+    /// no comma.
+    ///
+    /// ```
+    /// use human_name::{Name, NameOrder, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().name_order(NameOrder::SurnameFirst);
+    ///
+    /// let name = Name::parse_with("Kim Il-sung", &options).unwrap();
+    /// assert_eq!("Kim", name.surname());
+    /// assert_eq!(Some("Il-sung"), name.given_name());
+    ///
+    /// let name = Name::parse_with("DOE JOHN A JR", &options).unwrap();
+    /// assert_eq!("John A. Doe, Jr.", name.display_full());
+    /// ```
+    pub fn name_order(mut self, name_order: NameOrder) -> ParseOptions {
+        self.name_order = name_order;
+        self
+    }
+
+    /// Whether to recognize initials after the surname, without a comma, as
+    /// in Vancouver-style citations and PubMed (e.g. "Smith JA", "van der
+    /// Berg HJ" or "Doe J. Jr"). Off by default, since otherwise a short
+    /// all-caps surname may be taken for initials (e.g. "John AB").
+    ///
+    /// ```
+    /// use human_name::{Name, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().initials_after_surname(true);
+    /// let name = Name::parse_with("Smith JA", &options).unwrap();
+    /// assert_eq!("Smith", name.surname());
+    /// assert_eq!("JA", name.initials());
+    ///
+    /// let name = Name::parse("John AB").unwrap();
+    /// assert_eq!(Some("John"), name.given_name());
+    /// ```
+    pub fn initials_after_surname(mut self, initials_after_surname: bool) -> ParseOptions {
+        self.initials_after_surname = initials_after_surname;
+        self
+    }
+
+    /// Reject input of this many bytes or more, before attempting to parse
+    /// it (by default, `MAX_NAME_LEN`).
+    pub fn max_len(mut self, max_len: usize) -> ParseOptions {
+        self.max_len = max_len;
+        self
+    }
+}
+
+/// Options controlling how `Name::consistent_with_options` (and
+/// `PersonName::consistent_with_options`) compare names.
+///
+/// ```
+/// use human_name::{ComparisonOptions, Name};
+///
+/// let before = Name::parse("Jane Smith").unwrap();
+/// let after = Name::parse("Jane Doe (née Smith)").unwrap();
+/// assert!(!after.consistent_with(&before));
+///
+/// let options = ComparisonOptions::new().match_former_surnames(true);
+/// assert!(after.consistent_with_options(&before, &options));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ComparisonOptions {
+    pub(crate) match_former_surnames: bool,
+    pub(crate) match_mononyms: bool,
+    pub(crate) match_romanized_surnames: bool,
+    pub(crate) match_equivalent_names: bool,
+    pub(crate) match_double_surname_parts: bool,
+    pub(crate) nickname_locales: Vec<Locale>,
+}
+
+impl ComparisonOptions {
+    /// The default options, equivalent to those used by `consistent_with`.
+    pub fn new() -> ComparisonOptions {
+        ComparisonOptions::default()
+    }
+
+    /// Whether a former surname (see `Name::former_surnames`) of either name,
+    /// including a birth surname, may satisfy the surname check in place of
+    /// the current surname.
+    ///
+    /// Note that names which are consistent only because of a former surname
+    /// will generally have different `surname_hash` values.
+    pub fn match_former_surnames(mut self, match_former_surnames: bool) -> ComparisonOptions {
+        self.match_former_surnames = match_former_surnames;
+        self
+    }
+
+    /// Whether a single-word name (see `PersonName::Mononym`) may be
+    /// consistent with a full name, if it matches either the given name
+    /// (allowing for nicknames) or the surname. By default, a mononym is
+    /// only consistent with another mononym.
+    pub fn match_mononyms(mut self, match_mononyms: bool) -> ComparisonOptions {
+        self.match_mononyms = match_mononyms;
+        self
+    }
+
+    /// Whether single-word surnames which are different romanizations of the
+    /// same Chinese or Korean surname (e.g. "Zhang", "Chang" and "Cheung", or
+    /// "Lee", "Yi" and "Rhee") may satisfy the surname check.
+    ///
+    /// Note that names which are consistent only because of a romanization
+    /// will generally have different `surname_hash` values.
+    ///
+    /// ```
+    /// use human_name::{ComparisonOptions, Name};
+    ///
+    /// let park = Name::parse("Ji-sung Park").unwrap();
+    /// let bak = Name::parse("Ji-sung Bak").unwrap();
+    /// assert!(!park.consistent_with(&bak));
+    ///
+    /// let options = ComparisonOptions::new().match_romanized_surnames(true);
+    /// assert!(park.consistent_with_options(&bak, &options));
+    /// ```
+    pub fn match_romanized_surnames(mut self, match_romanized_surnames: bool) -> ComparisonOptions {
+        self.match_romanized_surnames = match_romanized_surnames;
+        self
+    }
+
+    /// Whether given and middle names may match another language's form of
+    /// the same name (e.g. "John", "Juan", "Johann", "Giovanni" and "Ivan"),
+    /// as when records switch between languages.
+    ///
+    /// ```
+    /// use human_name::{ComparisonOptions, Name};
+    ///
+    /// let giovanni = Name::parse("Giovanni Battista Rossi").unwrap();
+    /// let john = Name::parse("John B. Rossi").unwrap();
+    /// assert!(!giovanni.consistent_with(&john));
+    ///
+    /// let options = ComparisonOptions::new().match_equivalent_names(true);
+    /// assert!(giovanni.consistent_with_options(&john, &options));
+    /// assert!(!giovanni.consistent_with_options(&Name::parse("Joseph Rossi").unwrap(), &options));
+    /// ```
+    pub fn match_equivalent_names(mut self, match_equivalent_names: bool) -> ComparisonOptions {
+        self.match_equivalent_names = match_equivalent_names;
+        self
+    }
+
+    /// Whether one part of a double surname in the Spanish or Portuguese
+    /// style (see `Name::paternal_surname`) may satisfy the surname check in
+    /// place of the whole: the paternal part, for names parsed with
+    /// `Locale::Es` or `Locale::Pt`, or else either part.
+    ///
+    /// Note that names which are consistent only because of one part of a
+    /// double surname will generally have different `surname_hash` values.
+    ///
+    /// ```
+    /// use human_name::{ComparisonOptions, Locale, Name, ParseOptions};
+    ///
+    /// let spanish = ParseOptions::new().locale(Locale::Es);
+    /// let full = Name::parse_with("Gabriel García Márquez", &spanish).unwrap();
+    /// let paternal = Name::parse_with("Gabriel García", &spanish).unwrap();
+    /// assert!(!full.consistent_with(&paternal));
+    ///
+    /// let options = ComparisonOptions::new().match_double_surname_parts(true);
+    /// assert!(full.consistent_with_options(&paternal, &options));
+    /// ```
+    pub fn match_double_surname_parts(
+        mut self,
+        match_double_surname_parts: bool,
+    ) -> ComparisonOptions {
+        self.match_double_surname_parts = match_double_surname_parts;
+        self
+    }
+
+    /// Also accept nicknames particular to the locale's language, such as
+    /// "Pepe" for "José" or "Paco" for "Francisco" in Spanish. May be given
+    /// more than one locale; `Locale::Any` accepts nicknames from all of them.
+    ///
+    /// Nicknames are available for German (`Locale::De`), Spanish
+    /// (`Locale::Es`), Italian (`Locale::It`), and Russian (`Locale::Ru`),
+    /// each only if compiled in with the matching cargo feature (e.g.
+    /// `nicknames-es`). Other locales have no effect.
+    ///
+    /// ```
+    /// use human_name::{ComparisonOptions, Locale, Name};
+    ///
+    /// let francisco = Name::parse("Francisco Franco").unwrap();
+    /// let paco = Name::parse("Paco Franco").unwrap();
+    /// assert!(!francisco.consistent_with(&paco));
+    ///
+    /// let options = ComparisonOptions::new().match_nicknames_from(Locale::Es);
+    /// assert_eq!(
+    ///     cfg!(feature = "nicknames-es"),
+    ///     francisco.consistent_with_options(&paco, &options)
+    /// );
+    /// ```
+    pub fn match_nicknames_from(mut self, locale: Locale) -> ComparisonOptions {
+        self.nickname_locales.push(locale);
+        self
+    }
+}
@@ -0,0 +1,463 @@
+use super::case::is_mixed_case;
+use super::decomposition::is_combining;
+use super::namecase::namecase;
+use super::nickname::{have_matching_variants, Bracketed};
+use std::borrow::Cow;
+use unicode_normalization::UnicodeNormalization;
+
+// Words introducing a birth surname, compared after lowercasing and
+// dropping accents and trailing punctuation
+const BIRTH_SURNAME_MARKERS: [&str; 10] = [
+    "nee",
+    "nata",
+    "nato",
+    "nacida",
+    "nacido",
+    "geb",
+    "geborene",
+    "geborener",
+    "born",
+    "maiden",
+];
+
+// Words introducing some other former surname
+const FORMER_SURNAME_MARKERS: [&str; 6] = ["formerly", "previously", "fka", "f/k/a", "ex", "z"];
+
+// Words after a marker which introduce where or when the person was born,
+// rather than a surname (e.g. "born in Paris")
+const BIRTH_DETAIL_WORDS: [&str; 4] = ["in", "at", "on", "circa"];
+
+// Words that, anywhere in a span, suggest it names an organization
+const ORGANIZATION_WORDS: [&str; 34] = [
+    "inc",
+    "corp",
+    "corporation",
+    "company",
+    "llc",
+    "llp",
+    "ltd",
+    "limited",
+    "plc",
+    "gmbh",
+    "bv",
+    "group",
+    "holdings",
+    "partners",
+    "associates",
+    "consulting",
+    "services",
+    "technologies",
+    "labs",
+    "bank",
+    "university",
+    "univ",
+    "college",
+    "school",
+    "institute",
+    "hospital",
+    "clinic",
+    "foundation",
+    "society",
+    "association",
+    "council",
+    "ministry",
+    "department",
+    "dept",
+];
+
+// Organization suffixes which are also short names or nicknames (e.g. "Co"),
+// so only count at the end, after another word, with a period or in capitals
+// (e.g. "Acme Co." or "Siemens AG")
+const AMBIGUOUS_ORGANIZATION_WORDS: [&str; 4] = ["co", "ag", "sa", "lp"];
+
+/// What a quoted or bracketed part of the input seems to contain
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParentheticalKind {
+    /// A name the person goes by, e.g. "Bob" in "Robert (Bob) Roberts"
+    Nickname,
+    /// A former surname, e.g. "Smith" in "Jane Doe (née Smith)"
+    FormerSurname,
+    /// An employer or other affiliation, e.g. "Acme Corp" in "John Doe (Acme Corp)"
+    Organization,
+    /// Anything we couldn't classify
+    Unknown,
+}
+
+/// A quoted or bracketed part of the input, which is not part of the name
+/// proper but may describe the person.
+///
+/// ```
+/// use human_name::{Name, ParentheticalKind};
+///
+/// let name = Name::parse("Jane Doe (née Smith)").unwrap();
+/// let parenthetical = &name.parentheticals()[0];
+/// assert_eq!(ParentheticalKind::FormerSurname, parenthetical.kind());
+/// assert_eq!("Smith", parenthetical.text());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Parenthetical {
+    kind: ParentheticalKind,
+    text: Box<str>,
+    birth: bool,
+}
+
+impl Parenthetical {
+    /// How we classified the content
+    pub fn kind(&self) -> ParentheticalKind {
+        self.kind
+    }
+
+    /// The content, without brackets, quotes, or (for former surnames)
+    /// markers like "née"
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub(crate) fn is_birth_surname(&self) -> bool {
+        self.birth
+    }
+}
+
+pub fn classify(bracketed: &Bracketed, given_name: Option<&str>, input: &str) -> Parenthetical {
+    let words: Vec<&str> = bracketed.text.split_whitespace().collect();
+
+    let (marker_len, birth) = former_surname_marker_len(&words);
+    if marker_len > 0 && is_surname_like(&words[marker_len..]) {
+        return Parenthetical {
+            kind: ParentheticalKind::FormerSurname,
+            text: namecase_words(&words[marker_len..], input),
+            birth,
+        };
+    }
+
+    let kind = if is_organization(&words) {
+        ParentheticalKind::Organization
+    } else if words.len() > 3 || !words.iter().all(|w| is_namelike(w)) {
+        ParentheticalKind::Unknown
+    } else if bracketed.quoted || !bracketed.trailing {
+        ParentheticalKind::Nickname
+    } else if words.len() == 1 && is_variant_of(words[0], given_name, input) {
+        // After the surname, a known variant of the given name is a nickname,
+        // as in "Robert Roberts (Bob)", but anything else is more likely the
+        // surname the person used to go by, as in "Maria Lopez (Garcia)"
+        ParentheticalKind::Nickname
+    } else if words.len() <= 2 {
+        ParentheticalKind::FormerSurname
+    } else {
+        ParentheticalKind::Unknown
+    };
+
+    let text = match kind {
+        ParentheticalKind::Nickname | ParentheticalKind::FormerSurname => {
+            namecase_words(&words, input)
+        }
+        _ => words.join(" ").into_boxed_str(),
+    };
+
+    Parenthetical {
+        kind,
+        text,
+        birth: false,
+    }
+}
+
+// Splits a birth surname introduced by a marker outside of brackets, as in
+// "Jane Smith née Doe" or "Anna Müller geb. Schmidt, PhD", from the rest of
+// the name. Requires at least two words before the marker, so that we don't
+// mistake, e.g., "Max Born" for a marker.
+pub fn split_birth_surname(input: &str) -> Option<(String, Box<str>)> {
+    let mut offset = 0;
+    for (preceding, word) in input.split(' ').filter(|word| !word.is_empty()).enumerate() {
+        let start = offset + input[offset..].find(word)?;
+        offset = start + word.len();
+
+        if preceding >= 2
+            && !word.ends_with(',')
+            && (is_birth_surname_marker(word) || matches_any(word, &["z"]))
+        {
+            let (rest, after) = match input[start..].find(',') {
+                Some(i) => (&input[start..start + i], &input[start + i..]),
+                None => (&input[start..], ""),
+            };
+
+            let words: Vec<&str> = rest.split_whitespace().collect();
+            let (marker_len, birth) = former_surname_marker_len(&words);
+            let surname_words = &words[marker_len..];
+            if !birth || !is_surname_like(surname_words) {
+                return None;
+            }
+
+            let mut remaining = input[..start].trim_end().to_string();
+            remaining.push_str(after);
+            return Some((remaining, namecase_words(surname_words, input)));
+        }
+    }
+
+    None
+}
+
+// What follows a marker should be a short name, not a place or date (e.g. "in
+// Paris" or "1960")
+fn is_surname_like(words: &[&str]) -> bool {
+    !words.is_empty()
+        && words.len() <= 3
+        && words.iter().all(|w| is_namelike(w))
+        && !matches_any(words[0], &BIRTH_DETAIL_WORDS)
+}
+
+fn former_surname_marker_len(words: &[&str]) -> (usize, bool) {
+    let birth = match words.first() {
+        Some(word) if is_birth_surname_marker(word) => true,
+        Some(word) if matches_any(word, &FORMER_SURNAME_MARKERS) => false,
+        _ => return (0, false),
+    };
+
+    // E.g. "maiden name:", "z domu", "ex-"
+    let len = 1 + words[1..]
+        .iter()
+        .take_while(|word| matches_any(word, &["name", "domu", ""]))
+        .count();
+
+    // Single-letter markers only count along with what follows (as in "z domu")
+    if len == 1 && words[0].chars().count() == 1 {
+        (0, false)
+    } else {
+        (len, birth || (len > 1 && matches_any(words[1], &["domu"])))
+    }
+}
+
+// "Né" (the masculine of "née") is also a name, so it only counts with its
+// accent or a period
+fn is_birth_surname_marker(word: &str) -> bool {
+    matches_any(word, &BIRTH_SURNAME_MARKERS)
+        || (matches_any(word, &["ne"]) && (!word.is_ascii() || word.ends_with('.')))
+}
+
+fn is_organization(words: &[&str]) -> bool {
+    if words
+        .iter()
+        .any(|w| ORGANIZATION_WORDS.contains(&&*normalize(w)) || *w == "&")
+    {
+        return true;
+    }
+
+    match words {
+        [_, .., last] => {
+            (last.ends_with('.') || !last.chars().any(char::is_lowercase))
+                && AMBIGUOUS_ORGANIZATION_WORDS.contains(&&*normalize(last))
+        }
+        _ => false,
+    }
+}
+
+// Compares without allocating, since we check most words of most names
+fn matches_any(word: &str, markers: &[&str]) -> bool {
+    let word = word.trim_end_matches(|c| c == '.' || c == ':' || c == ',' || c == '-');
+
+    // Only decompose when there might be accents to drop
+    if word.is_ascii() {
+        return markers
+            .iter()
+            .any(|marker| marker.eq_ignore_ascii_case(word));
+    }
+
+    markers.iter().any(|marker| {
+        word.nfkd()
+            .filter(|c| !is_combining(*c))
+            .flat_map(char::to_lowercase)
+            .eq(marker.chars())
+    })
+}
+
+fn normalize(word: &str) -> Cow<'_, str> {
+    let trimmed = word.trim_end_matches(|c| c == '.' || c == ':' || c == ',' || c == '-');
+    if trimmed.bytes().all(|b| b.is_ascii_lowercase() || b == b'/') {
+        Cow::Borrowed(trimmed)
+    } else {
+        Cow::Owned(
+            trimmed
+                .nfkd()
+                .filter(|c| !is_combining(*c))
+                .flat_map(char::to_lowercase)
+                .collect(),
+        )
+    }
+}
+
+fn is_namelike(word: &str) -> bool {
+    word.chars()
+        .all(|c| c.is_alphabetic() || is_combining(c) || "-'’.".contains(c))
+        && word.chars().any(char::is_alphabetic)
+}
+
+fn is_variant_of(word: &str, given_name: Option<&str>, input: &str) -> bool {
+    match given_name {
+        Some(given_name) => have_matching_variants(&namecase_words(&[word], input), given_name),
+        None => false,
+    }
+}
+
+// Capitalizes word by word, unless the full input was already mixed-case (in
+// which case we trust its capitalization, e.g. "JJ")
+fn namecase_words(words: &[&str], input: &str) -> Box<str> {
+    if is_mixed_case(input) {
+        return words.join(" ").into_boxed_str();
+    }
+
+    words
+        .iter()
+        .enumerate()
+        .map(|(i, word)| {
+            namecase(
+                word,
+                word.chars().all(|c| c.is_ascii_alphabetic()),
+                i < words.len() - 1,
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+        .into_boxed_str()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nickname::find_bracketed;
+
+    fn classify_all(input: &str, given_name: Option<&str>) -> Vec<(ParentheticalKind, String)> {
+        find_bracketed(input)
+            .iter()
+            .map(|b| classify(b, given_name, input))
+            .map(|p| (p.kind, p.text.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn nicknames() {
+        assert_eq!(
+            vec![(ParentheticalKind::Nickname, "Bob".to_string())],
+            classify_all("Robert (Bob) Roberts", Some("Robert"))
+        );
+        assert_eq!(
+            vec![(ParentheticalKind::Nickname, "Bob".to_string())],
+            classify_all("Robert Roberts (Bob)", Some("Robert"))
+        );
+        assert_eq!(
+            vec![(ParentheticalKind::Nickname, "Chip".to_string())],
+            classify_all("WILLIAM 'CHIP' SMITH", Some("William"))
+        );
+    }
+
+    #[test]
+    fn former_surnames() {
+        assert_eq!(
+            vec![(ParentheticalKind::FormerSurname, "Smith".to_string())],
+            classify_all("Jane Doe (née Smith)", Some("Jane"))
+        );
+        assert_eq!(
+            vec![(ParentheticalKind::FormerSurname, "Schmidt".to_string())],
+            classify_all("Anna Müller (geb. Schmidt)", Some("Anna"))
+        );
+        assert_eq!(
+            vec![(ParentheticalKind::FormerSurname, "Garcia".to_string())],
+            classify_all("Maria Lopez (Garcia)", Some("Maria"))
+        );
+        assert_eq!(
+            vec![(ParentheticalKind::FormerSurname, "MacDonald".to_string())],
+            classify_all("JANE DOE (NEE MACDONALD)", Some("Jane"))
+        );
+        assert_eq!(
+            vec![(ParentheticalKind::FormerSurname, "Kowalska".to_string())],
+            classify_all("Anna Nowak (z domu Kowalska)", Some("Anna"))
+        );
+
+        // Not surnames
+        assert_eq!(
+            vec![(ParentheticalKind::Unknown, "born in Paris".to_string())],
+            classify_all("Jane Doe (born in Paris)", Some("Jane"))
+        );
+        assert_eq!(
+            vec![(ParentheticalKind::Unknown, "born 1960".to_string())],
+            classify_all("Jane Smith (born 1960)", Some("Jane"))
+        );
+        assert_eq!(
+            vec![(ParentheticalKind::Unknown, "nee 1950".to_string())],
+            classify_all("Jane Doe (nee 1950)", Some("Jane"))
+        );
+    }
+
+    #[test]
+    fn birth_surnames() {
+        let split =
+            |input| split_birth_surname(input).map(|(rest, surname)| (rest, surname.to_string()));
+
+        assert_eq!(
+            Some(("Jane Smith".to_string(), "Doe".to_string())),
+            split("Jane Smith née Doe")
+        );
+        assert_eq!(
+            Some(("Anna Müller, PhD".to_string(), "Schmidt".to_string())),
+            split("Anna Müller geb. Schmidt, PhD")
+        );
+        assert_eq!(
+            Some(("ANNA NOWAK".to_string(), "Kowalska".to_string())),
+            split("ANNA NOWAK Z DOMU KOWALSKA")
+        );
+        assert_eq!(None, split("Max Born"));
+        assert_eq!(None, split("Born Smith"));
+        assert_eq!(None, split("Jane Smith née"));
+        assert_eq!(None, split("Jane Smith formerly Doe"));
+        assert_eq!(
+            Some(("Jean Dupont".to_string(), "Martin".to_string())),
+            split("Jean Dupont né Martin")
+        );
+        assert_eq!(
+            Some(("Jean Dupont".to_string(), "Martin".to_string())),
+            split("Jean Dupont ne. Martin")
+        );
+        assert_eq!(None, split("Tran Van Ne Nguyen"));
+    }
+
+    #[test]
+    fn organizations() {
+        assert_eq!(
+            vec![(ParentheticalKind::Organization, "Acme Corp".to_string())],
+            classify_all("John Doe (Acme Corp)", Some("John"))
+        );
+        assert_eq!(
+            vec![(
+                ParentheticalKind::Organization,
+                "Smith & Wesson".to_string()
+            )],
+            classify_all("John Doe (Smith & Wesson)", Some("John"))
+        );
+        assert_eq!(
+            vec![(ParentheticalKind::Organization, "Acme Co.".to_string())],
+            classify_all("John Doe (Acme Co.)", Some("John"))
+        );
+        assert_eq!(
+            vec![(ParentheticalKind::Organization, "Siemens AG".to_string())],
+            classify_all("John Doe (Siemens AG)", Some("John"))
+        );
+        assert_eq!(
+            vec![(ParentheticalKind::Nickname, "Co".to_string())],
+            classify_all("Colin (Co) Doe", Some("Colin"))
+        );
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(
+            vec![(ParentheticalKind::Unknown, "1990-2020".to_string())],
+            classify_all("John Doe (1990-2020)", Some("John"))
+        );
+        assert_eq!(
+            vec![(
+                ParentheticalKind::Unknown,
+                "see also John Smith".to_string()
+            )],
+            classify_all("John Doe (see also John Smith)", Some("John"))
+        );
+    }
+}
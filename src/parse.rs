@@ -1,18 +1,34 @@
+use super::arabic;
 use super::case::is_mixed_case;
-use super::namepart::{Location, NamePart};
+use super::cjk;
+use super::error::ParseError;
+use super::features::categorize_chars;
+use super::flags::ParseFlags;
+use super::namepart::{Category, Location, NamePart};
+use super::nickname;
+use super::options::{Locale, NameOrder, ParseOptions};
+use super::patronymic;
+use super::romanization;
 use super::suffix;
-use super::surname;
+use super::surname::{self, SurnameOrder};
 use super::title;
 use crate::Cow;
 use smallvec::SmallVec;
 use std::num::NonZeroU8;
+use std::ops::Range;
 
 pub struct Name<'a> {
     parts: SmallVec<[NamePart<'a>; 7]>,
     pub surname_index: usize,
     pub generation: Option<NonZeroU8>,
+    pub generation_word: Option<&'a str>,
+    pub patronymic: Option<Range<usize>>,
+    pub second_surname_index: Option<usize>,
+    pub surname_order: SurnameOrder,
+    pub flags: ParseFlags,
     reversed_prefixes: Vec<NamePart<'a>>,
     honorific_suffixes: Vec<NamePart<'a>>,
+    kunya: Vec<NamePart<'a>>,
 }
 
 impl<'a> Name<'a> {
@@ -20,7 +36,7 @@ impl<'a> Name<'a> {
         self.parts.as_ref()
     }
 
-    pub fn honorific_prefix(&self) -> Option<Cow<str>> {
+    pub fn honorific_prefix(&self) -> Option<Cow<'_, str>> {
         match self.reversed_prefixes.len() {
             0 => None,
             1 => self
@@ -38,7 +54,7 @@ impl<'a> Name<'a> {
         }
     }
 
-    pub fn honorific_suffix(&self) -> Option<Cow<str>> {
+    pub fn honorific_suffix(&self) -> Option<Cow<'_, str>> {
         match self.honorific_suffixes.len() {
             0 => None,
             1 => self
@@ -54,47 +70,205 @@ impl<'a> Name<'a> {
             )),
         }
     }
+
+    pub fn honorific_prefix_words(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.reversed_prefixes.iter().rev().map(|w| w.word)
+    }
+
+    pub fn honorific_suffix_words(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.honorific_suffixes.iter().map(|w| w.word)
+    }
+
+    pub fn kunya_words(&self) -> &[NamePart<'a>] {
+        &self.kunya
+    }
+
+    /// The earliest the surname might plausibly start, if not at
+    /// `surname_index` (e.g. "Garcia Lopez" rather than just "Lopez" in
+    /// "Juan Garcia Lopez")
+    pub fn earliest_surname_index(&self) -> usize {
+        (1..self.surname_index)
+            .rev()
+            .take_while(|&i| self.parts[i].is_namelike())
+            .last()
+            .unwrap_or(self.surname_index)
+    }
 }
 
+/// A variation on the default heuristics, used to enumerate alternative
+/// readings of ambiguous input
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Reading {
+    /// Parse as if the input were all one case, even if it is mixed-case
+    pub ignore_capitalization: bool,
+
+    /// Keep words which look like prefix or postfix titles as part of the
+    /// name, taking any postfix for the surname
+    pub keep_titles: bool,
+
+    /// Treat the first word as the surname, if there is no comma
+    pub surname_first: bool,
+}
+
+/// Readings to try when enumerating candidate parses, with a prior reflecting
+/// how likely each is relative to the default
+pub const READINGS: [(Reading, f32); 4] = [
+    (
+        Reading {
+            ignore_capitalization: false,
+            keep_titles: false,
+            surname_first: false,
+        },
+        1.0,
+    ),
+    (
+        Reading {
+            ignore_capitalization: true,
+            keep_titles: false,
+            surname_first: false,
+        },
+        0.8,
+    ),
+    (
+        Reading {
+            ignore_capitalization: false,
+            keep_titles: false,
+            surname_first: true,
+        },
+        0.6,
+    ),
+    (
+        Reading {
+            ignore_capitalization: false,
+            keep_titles: true,
+            surname_first: false,
+        },
+        0.5,
+    ),
+];
+
+/// How much less likely each word by which we extend the surname leftwards
+/// is, relative to the default guess
+pub const ALTERNATE_SURNAME_PRIOR: f32 = 0.5;
+
 #[derive(Debug)]
 struct ParseOp<'a> {
     // Output
     words: SmallVec<[NamePart<'a>; 7]>,
     surname_index: usize,
     generation_from_suffix: Option<NonZeroU8>,
+    generation_word: Option<&'a str>,
     reversed_prefixes: Vec<NamePart<'a>>,
     honorific_suffixes: Vec<NamePart<'a>>,
+    kunya: Vec<NamePart<'a>>,
+    flags: ParseFlags,
 
     // Working space
+    reading: Reading,
+    locale: Locale,
+    name_order: NameOrder,
+    initials_after_surname: bool,
     use_capitalization: bool,
+    reversed_discards: Vec<NamePart<'a>>,
 }
 
-pub const MAX_WORDS: usize = u8::max_value() as usize;
+pub const MAX_WORDS: usize = u8::MAX as usize;
 
-pub fn parse(name: &str) -> Option<Name> {
-    let mut op = ParseOp {
-        words: SmallVec::new(),
-        surname_index: 0,
-        generation_from_suffix: None,
-        reversed_prefixes: Vec::new(),
-        honorific_suffixes: Vec::new(),
-        use_capitalization: is_mixed_case(name),
-    };
+#[cfg(test)]
+pub fn parse(name: &str) -> Result<Name<'_>, ParseError> {
+    parse_with(name, &ParseOptions::default(), Reading::default())
+}
 
+pub fn parse_with<'a>(
+    name: &'a str,
+    options: &ParseOptions,
+    reading: Reading,
+) -> Result<Name<'a>, ParseError> {
+    let mut op = ParseOp::new(name, options, reading);
     if op.run(name) {
-        Some(Name {
-            parts: op.words,
-            surname_index: op.surname_index,
-            generation: op.generation_from_suffix,
-            reversed_prefixes: op.reversed_prefixes,
-            honorific_suffixes: op.honorific_suffixes,
-        })
+        Ok(op.into_name())
     } else {
-        None
+        Err(op.error())
     }
 }
 
+// Like `parse_with`, but succeeds only where that would fail because just one
+// name word was left after stripping titles and suffixes (e.g. "Dr. Sukarno"),
+// or would succeed only by taking a title for part of the name
+pub fn parse_single_word<'a>(name: &'a str, options: &ParseOptions) -> Option<Name<'a>> {
+    let mut op = ParseOp::new(name, options, Reading::default());
+    if op.run(name) {
+        // E.g. "Dr Smith", where we only took "Dr" for initials for lack of
+        // any other given name
+        if op.words.len() != 2 || !op.flags.contains(ParseFlags::FALSE_PREFIX) {
+            return None;
+        }
+        let prefix = op.words.remove(0);
+        op.reversed_prefixes.push(prefix);
+        op.surname_index = 0;
+    } else if op.words.len() != 1 || !op.words[0].is_namelike() {
+        return None;
+    }
+
+    // Trailing words we'd have discarded as stray postfixes (when there
+    // was no room for them to be titles) are titles after all, unless junk
+    while let Some(discard) = op.reversed_discards.pop() {
+        if !matches!(discard.category, Category::Other) {
+            op.found_suffix_or_postfix(discard, false);
+        }
+    }
+
+    Some(op.into_name())
+}
+
 impl<'a> ParseOp<'a> {
+    fn new(name: &str, options: &ParseOptions, reading: Reading) -> ParseOp<'a> {
+        let use_capitalization = match options.trust_capitalization {
+            Some(trust) => trust,
+            None => is_mixed_case(name),
+        };
+
+        ParseOp {
+            words: SmallVec::new(),
+            surname_index: 0,
+            generation_from_suffix: None,
+            generation_word: None,
+            reversed_prefixes: Vec::new(),
+            honorific_suffixes: Vec::new(),
+            kunya: Vec::new(),
+            flags: ParseFlags::empty(),
+            reading,
+            locale: options.locale,
+            name_order: options.name_order,
+            initials_after_surname: options.initials_after_surname,
+            use_capitalization: use_capitalization && !reading.ignore_capitalization,
+            reversed_discards: Vec::new(),
+        }
+    }
+
+    fn into_name(mut self) -> Name<'a> {
+        let patronymic = self.find_patronymic();
+        if let Some(range) = patronymic.clone().filter(|r| r.end <= self.surname_index) {
+            self.lowercase_particles(range);
+        }
+        let second_surname_index =
+            surname::find_second_surname(&self.words[self.surname_index..], self.locale)
+                .map(|i| self.surname_index + i);
+        Name {
+            parts: self.words,
+            surname_index: self.surname_index,
+            generation: self.generation_from_suffix,
+            generation_word: self.generation_word,
+            patronymic,
+            second_surname_index,
+            surname_order: SurnameOrder::for_locale(self.locale),
+            flags: self.flags,
+            reversed_prefixes: self.reversed_prefixes,
+            honorific_suffixes: self.honorific_suffixes,
+            kunya: self.kunya,
+        }
+    }
+
     /// Responsible for the main parse operation: segments input by commas,
     /// then by word separators, while assigning a preliminary categorization
     /// to each segment, and then determines a final categorization for each
@@ -106,9 +280,11 @@ impl<'a> ParseOp<'a> {
         // Separate comma-separated titles and suffixes, then flip remaining words
         // around remaining comma, if any
         let mut parts = name.split(',').peekable();
+        let mut part_count = 0;
         while let Some(part) = parts.next() {
             let first_part = self.words.is_empty();
             let last_part = parts.peek().is_none();
+            part_count += 1;
 
             if first_part && last_part {
                 // Simple case
@@ -124,6 +300,12 @@ impl<'a> ParseOp<'a> {
                 // initials
                 let must_include_given = last_part && self.words.len() == 1;
                 self.handle_after_comma(part, must_include_given);
+            } else if part_count == 2 && last_part && self.is_bare_name() && only_initials(part) {
+                // We thought the before-comma part was the full name, but
+                // it's followed by nothing but initials, so it must have been
+                // a multi-word surname (e.g. "Garcia Marquez, G.")
+                self.surname_index = 0;
+                self.handle_after_comma(part, true);
             } else {
                 // We already found the full name, so this is a comma-separated
                 // postfix title or suffix
@@ -131,6 +313,10 @@ impl<'a> ParseOp<'a> {
             }
         }
 
+        if self.reading.keep_titles {
+            self.keep_titles();
+        }
+
         // If there are two or fewer words, e.g. "JOHN MA", we treat
         // ambiguous strings like "MA" as surnames rather than titles
         // (not initials, which should only be at the end of the input
@@ -138,11 +324,15 @@ impl<'a> ParseOp<'a> {
         if !self.valid() {
             if let Some(i) = self.possible_false_postfix() {
                 self.words.push(self.honorific_suffixes.remove(i));
+                self.flags |= ParseFlags::FALSE_POSTFIX;
             } else if let Some(i) = self.possible_false_prefix() {
                 self.words.insert(0, self.reversed_prefixes.remove(i));
+                self.flags |= ParseFlags::FALSE_PREFIX;
             }
         }
 
+        let surname_guess_failed = self.surname_index == 0;
+
         // Anything trailing that looks like initials is probably a stray postfix
         while self.words.last().filter(|w| !w.is_namelike()).is_some() {
             let removed = self.words.pop().unwrap();
@@ -158,20 +348,71 @@ impl<'a> ParseOp<'a> {
                 let word = NamePart::from_word(removed.word, false, Location::End);
                 if word.is_namelike() {
                     self.words.push(word);
+                    self.flags |= ParseFlags::IGNORED_CAPITALIZATION;
                     break;
                 }
             }
+
+            self.reversed_discards.push(removed);
+            self.flags |= ParseFlags::DISCARDED_POSTFIX;
         }
 
         // Handle case where we thought the whole before-comma part was a surname,
         // but we never found a plausible given name or initial afterwards,
         // as well as the reset just above
         if self.surname_index == 0 && self.words.len() > 1 {
-            self.surname_index = surname::find_surname_index(&self.words[1..]) + 1;
+            self.surname_index = surname::find_surname_index(&self.words[1..], self.locale) + 1;
+            if surname_guess_failed {
+                self.flags |= ParseFlags::REVISED_SURNAME;
+            }
         }
 
         // Check the plausibility of what we've found
-        self.valid()
+        if !self.valid() {
+            return false;
+        }
+
+        // Record whether we had to guess that any given or middle names were
+        // actually initials, as opposed to single letters or e.g. "J.M."
+        if self.words[..self.surname_index]
+            .iter()
+            .any(|w| w.is_initials() && w.counts.chars > 1 && !w.word.contains('.'))
+        {
+            self.flags |= ParseFlags::GUESSED_INITIALS;
+        }
+
+        true
+    }
+
+    // Treat any words we took for titles as part of the name instead, however
+    // they're written (e.g. "MD" in "John Smith MD", or "Dr." in "Smith, Dr.
+    // John"), with a kept postfix taking the place of the surname
+    fn keep_titles(&mut self) {
+        if !self.honorific_suffixes.is_empty() {
+            self.surname_index = self.words.len();
+            self.words
+                .extend(self.honorific_suffixes.drain(..).map(title_as_name));
+            self.flags |= ParseFlags::FALSE_POSTFIX;
+        }
+
+        if !self.reversed_prefixes.is_empty() {
+            let len = self.reversed_prefixes.len();
+            for prefix in self.reversed_prefixes.drain(..) {
+                self.words.insert(0, title_as_name(prefix));
+            }
+            if self.surname_index > 0 {
+                self.surname_index += len;
+            }
+            self.flags |= ParseFlags::FALSE_PREFIX;
+        }
+    }
+
+    // Whether we've found name words and nothing else so far
+    fn is_bare_name(&self) -> bool {
+        self.reversed_prefixes.is_empty()
+            && self.honorific_suffixes.is_empty()
+            && self.generation_from_suffix.is_none()
+            && self.words.iter().all(|w| w.is_namelike())
     }
 
     fn valid(&self) -> bool {
@@ -186,6 +427,41 @@ impl<'a> ParseOp<'a> {
                 .any(|w| w.is_namelike())
     }
 
+    // Called only after `run` fails, to explain why
+    #[cold]
+    fn error(&self) -> ParseError {
+        match self.words.len() {
+            0 if self.reversed_prefixes.is_empty()
+                && self.honorific_suffixes.is_empty()
+                && self.reversed_discards.is_empty() =>
+            {
+                ParseError::NoWords
+            }
+            0 => {
+                // Anything we discarded as a stray postfix was in the position
+                // of a title as well; report everything in input order
+                let mut titles: Vec<&NamePart> = self
+                    .reversed_prefixes
+                    .iter()
+                    .chain(self.reversed_discards.iter())
+                    .chain(self.honorific_suffixes.iter())
+                    .collect();
+                titles.sort_by_key(|w| w.word.as_ptr() as usize);
+                ParseError::OnlyTitles {
+                    titles: titles.iter().map(|w| w.word.to_string()).collect(),
+                }
+            }
+            1 if self.words[0].is_namelike() || self.words[0].is_initials() => {
+                ParseError::SingleWord {
+                    word: self.words[0].word.to_string(),
+                }
+            }
+            _ => ParseError::Implausible {
+                words: self.words.iter().map(|w| w.word.to_string()).collect(),
+            },
+        }
+    }
+
     fn handle_no_comma(&mut self, name: &'a str) {
         debug_assert!(
             self.words.is_empty()
@@ -195,6 +471,16 @@ impl<'a> ParseOp<'a> {
             "Invalid state for handle_no_comma!"
         );
 
+        // Chinese, Japanese, and Korean names in native script (e.g. "毛泽东")
+        if cjk::is_cjk_locale(self.locale) {
+            if let Some((family_name, given_name)) = cjk::split_name(name, self.locale) {
+                self.words.push(cjk::name_part(given_name));
+                self.words.push(cjk::name_part(family_name));
+                self.surname_index = 1;
+                return;
+            }
+        }
+
         let mut in_prefix = true;
         for word in NamePart::all_from_text(name, self.use_capitalization, Location::Start) {
             if in_prefix && (word.is_namelike() || word.is_initials()) {
@@ -212,25 +498,412 @@ impl<'a> ParseOp<'a> {
             return;
         }
 
+        // In mixed-case input, a run of all-caps words is probably the surname
+        let mut capitalized_surname = self.find_capitalized_surname();
+
         // Check for title as prefix (e.g. "Dr. John Smith" or "Right Hon.
         // John Smith")
-        let prefix_title_len = if self.words.len() > 2 {
+        let mut prefix_title_len = if self.words.len() > 2 {
             title::find_prefix_len(&self.words)
         } else {
+            // Keep a would-be title as a given name (e.g. "Dean Smith"), but
+            // remember that we had to
+            if self.words.len() == 2
+                && capitalized_surname.is_none()
+                && title::find_prefix_len(&self.words) > 0
+            {
+                self.flags |= ParseFlags::FALSE_PREFIX;
+            }
             0
         };
+
+        if let Some(ref surname) = capitalized_surname {
+            // Don't let a title eat into the surname unless what's left still
+            // looks like a surname (e.g. "MR DUPONT Jean", but not "LE PEN
+            // Jean-Marie")
+            if prefix_title_len > surname.start {
+                prefix_title_len = (0..=prefix_title_len)
+                    .rev()
+                    .find(|&len| {
+                        title::find_prefix_len(&self.words[..=len]) == len
+                            && (len <= surname.start
+                                || self.is_capitalized_surname(len..surname.end))
+                    })
+                    .unwrap_or(0);
+            }
+        }
         self.strip_prefix(prefix_title_len);
 
-        // Strip non-comma-separated titles & suffixes (e.g. "John Smith Jr.")
-        let first_postfix_index =
-            if self.words.len() + self.possible_false_prefix().iter().count() > 2 {
-                title::find_postfix_index(&self.words[1..], false) + 1
+        if let Some(ref mut surname) = capitalized_surname {
+            surname.start = surname.start.saturating_sub(prefix_title_len);
+            surname.end -= prefix_title_len;
+        }
+
+        // If the surname comes first, trailing initials are middle initials
+        // rather than postfix titles (e.g. "DOE JOHN A")
+        let surname_first = self.name_order == NameOrder::SurnameFirst
+            || (capitalized_surname.is_none() && self.starts_with_family_name());
+
+        // Vancouver style, as in PubMed (e.g. "Smith JA" or "Doe J Jr")
+        if self.initials_after_surname
+            && capitalized_surname.is_none()
+            && !surname_first
+            && !self.reading.surname_first
+        {
+            if let Some((index, end)) = self.find_vancouver_initials() {
+                self.strip_postfix(end, true);
+                self.rotate_surname_to_end(index);
+                for initials in &mut self.words[..end - index] {
+                    *initials = NamePart::from_word_and_counts(
+                        initials.word,
+                        initials.counts.clone(),
+                        true,
+                        Location::Start,
+                    );
+                }
+                return;
+            }
+        }
+
+        // Strip non-comma-separated titles & suffixes (e.g. "John Smith Jr.").
+        // We don't take an all-caps surname for postfix titles just because
+        // it's in caps (e.g. "Anna Maria ROSSI"), only for trailing words we
+        // can recognize without relying on capitalization (e.g. "Jean DUPONT
+        // MD"), and not at all if that's all it is or it includes words that
+        // couldn't be part of a surname (e.g. "John Smith GBE KCB ADC DL")
+        let capitalized_surname = capitalized_surname.and_then(|surname| {
+            (surname.start + 1..=surname.end)
+                .find(|&end| {
+                    let (surname_words, postfixes) =
+                        self.words[surname.start..surname.end].split_at(end - surname.start);
+                    postfixes.iter().all(is_known_postfix)
+                        && !surname_words.iter().all(is_known_postfix)
+                        && surname_words.iter().all(|w| self.might_be_in_surname(w))
+                        && self.is_capitalized_surname(surname.start..end)
+                })
+                .map(|end| surname.start..end)
+        });
+
+        let first_postfix_index = if let Some(ref surname) = capitalized_surname {
+            surname.end + title::find_postfix_index(&self.words[surname.end..], surname_first)
+        } else if self.words.len() + self.possible_false_prefix().iter().count() > 2 {
+            title::find_postfix_index(&self.words[1..], surname_first) + 1
+        } else {
+            // Likewise keep a would-be postfix as a surname (e.g. "JOHN MA")
+            if self.words.len() == 2
+                && self.words[1].is_namelike()
+                && title::find_postfix_index(&self.words[1..], false) == 0
+            {
+                self.flags |= ParseFlags::FALSE_POSTFIX;
+            }
+            self.words.len()
+        };
+
+        self.strip_postfix(first_postfix_index, surname_first);
+
+        if let Some(surname) = capitalized_surname {
+            self.move_capitalized_surname_to_end(surname);
+            return;
+        }
+
+        if surname_first {
+            let surname_len = surname::find_surname_len(&self.words, self.locale);
+            self.rotate_surname_to_end(surname_len);
+            self.move_last_given_name_first();
+            return;
+        }
+
+        // Arabic names have a structure we can use to find the family name
+        // (e.g. "Abu Bakr Muhammad ibn Zakariya al-Razi")
+        if self.locale == Locale::Ar {
+            if let Some(structure) = arabic::find_structure(&self.words) {
+                self.kunya.extend(self.words.drain(..structure.kunya_len));
+                self.surname_index = structure.surname_index;
+                return;
+            }
+        }
+
+        // East Slavic names in official order, with the patronymic last (e.g.
+        // "Sidorov Ivan Petrovich"), but not English names with Slavic
+        // surnames (e.g. "Kayla Rose Markovich")
+        if self.words.len() == 3
+            && (self.locale == Locale::Ru || patronymic::has_slavic_surname_suffix(&self.words[0]))
+            && self.words.iter().all(|w| w.is_namelike())
+            && patronymic::has_slavic_suffix(&self.words[2])
+            && !patronymic::has_slavic_suffix(&self.words[1])
+            && !nickname::is_known_given_name(self.words[0].word)
+        {
+            self.rotate_surname_to_end(1);
+            return;
+        }
+
+        self.surname_index = surname::find_surname_index(&self.words[1..], self.locale) + 1;
+
+        // Spanish and Portuguese names usually have two surnames (e.g.
+        // "Gabriel García Márquez"), unless the word before the last is a
+        // given name (e.g. "Juan Carlos Pérez")
+        if matches!(self.locale, Locale::Es | Locale::Pt)
+            && self.surname_index > 1
+            && self.surname_index == self.words.len() - 1
+            && self.words[self.surname_index - 1..]
+                .iter()
+                .all(|w| w.is_namelike())
+            && !nickname::is_known_given_name(self.words[self.surname_index - 1].word)
+        {
+            self.surname_index -= 1;
+        }
+
+        // Unless we found particles or conjunctions that make the order clear
+        // (e.g. "Juan de la Vega"), try rotating a leading surname to the end
+        if self.reading.surname_first
+            && self.surname_index == self.words.len() - 1
+            && self.words[0].is_namelike()
+            && self.words[1].is_namelike()
+        {
+            self.rotate_surname_to_end(1);
+        }
+    }
+
+    // Finds the range of trailing initials after a surname, as in "Smith JA",
+    // "van der Berg HJ", or "Doe J Jr". We only trust a run of capitals to be
+    // initials rather than a name in mixed-case input, and we don't accept
+    // more than one word before the initials unless particles make it clear
+    // they're all the surname (since "John Smith J" is more likely "John J.
+    // Smith").
+    fn find_vancouver_initials(&self) -> Option<(usize, usize)> {
+        let mut end = self.words.len();
+        if end > 2 && suffix::generation_from_suffix(&self.words[end - 1], true).is_some() {
+            end -= 1;
+        }
+
+        // Allow for initials split by periods (e.g. "Smith J.A.")
+        let is_initials = |w: &NamePart| {
+            w.is_initials()
+                && w.counts.upper == w.counts.alpha
+                && (w.word.contains('.') || w.counts.alpha == 1 || self.use_capitalization)
+        };
+        let index = end
+            - self.words[..end]
+                .iter()
+                .rev()
+                .take_while(|w| is_initials(w))
+                .count();
+        if index == 0 || index == end {
+            return None;
+        }
+
+        let initials = &self.words[index..end];
+        let looks_like_initials = if initials.len() == 1 {
+            initials[0].counts.alpha <= 3
+                && !title::is_known_initialism(&initials[0])
+                && !surname::is_vowelless_surname(initials[0].word, false)
+                && suffix::generation_from_suffix(&initials[0], false).is_none()
+        } else {
+            initials.len() <= 3 && initials.iter().all(|w| w.counts.alpha == 1)
+        };
+
+        let surname = &self.words[..index];
+        let looks_like_surname = surname
+            .iter()
+            .all(|w| w.is_namelike() && w.word.starts_with(char::is_alphabetic))
+            && (surname.len() == 1 || surname::find_surname_index(surname, self.locale) == 0);
+
+        if looks_like_initials && looks_like_surname {
+            Some((index, end))
+        } else {
+            None
+        }
+    }
+
+    // Finds the words making up a patronymic, if any: a particle and the name
+    // after it (e.g. "bin Salman"), a Slavic patronymic among the middle names
+    // (e.g. "Ivan Petrovich Sidorov"), or a Nordic patronymic in place of a
+    // surname (e.g. "Björk Guðmundsdóttir")
+    fn find_patronymic(&self) -> Option<Range<usize>> {
+        let words = &self.words;
+        if words.len() < 2 {
+            return None;
+        }
+
+        // Including any further links in the chain (e.g. "bin Salman bin
+        // Abdulaziz")
+        let is_link = |i: usize| {
+            patronymic::is_particle(words[i].word, self.locale) && words[i + 1].is_namelike()
+        };
+        if let Some(start) = (1..words.len() - 1).find(|&i| is_link(i)) {
+            let mut end = start + 2;
+            while end + 1 < words.len() && is_link(end) {
+                end += 2;
+            }
+            return Some(start..end);
+        }
+
+        if let Some(i) = (1..self.surname_index)
+            .rev()
+            .find(|&i| patronymic::has_slavic_suffix(&words[i]))
+        {
+            return Some(i..i + 1);
+        }
+
+        // Before the surname (e.g. "Jon Einarsson Thorlacius"), or in its place
+        let surname_index = self.surname_index;
+        if surname_index > 1 && patronymic::has_nordic_suffix(&words[surname_index - 1], true) {
+            return Some(surname_index - 1..surname_index);
+        }
+        if surname_index > 0
+            && surname_index == words.len() - 1
+            && patronymic::has_nordic_suffix(&words[surname_index], false)
+        {
+            return Some(surname_index..words.len());
+        }
+
+        None
+    }
+
+    // Keep particles among the middle names lowercase, as we do for "van" or
+    // "de" (e.g. "bin Salman" rather than "Bin Salman")
+    fn lowercase_particles(&mut self, range: Range<usize>) {
+        for word in &mut self.words[range] {
+            if patronymic::is_particle(word.word, self.locale) {
+                word.category = Category::Name(Cow::Owned(word.word.to_lowercase()));
+            }
+        }
+    }
+
+    // In Hungarian and Vietnamese, a name starting with a common family name
+    // is probably in surname-first order (e.g. "Kovács János"), unless it
+    // also ends with one (e.g. "Nagy Tóth"). Vietnamese family names are
+    // rarely given names, so that's only ambiguous with just two words (e.g.
+    // "Lam Nguyen", but not "Tran Hung Dao").
+    fn starts_with_family_name(&self) -> bool {
+        let len = self.words.len();
+        len > 1
+            && surname::is_family_name(&self.words[0], self.locale)
+            && ((self.locale == Locale::Vi && len > 2)
+                || !surname::is_family_name(&self.words[len - 1], self.locale))
+    }
+
+    // Vietnamese names are addressed by the last given name (e.g. "Thieu" in
+    // "Nguyen Van Thieu"), so we put that first, as in the Western order
+    // "Thieu Van Nguyen"
+    fn move_last_given_name_first(&mut self) {
+        let given = &mut self.words[..self.surname_index];
+        if self.locale == Locale::Vi && given.len() > 1 && given.iter().all(|w| w.is_namelike()) {
+            given.rotate_right(1);
+        }
+    }
+
+    // Move the first `len` words to the end, as the surname
+    fn rotate_surname_to_end(&mut self, len: usize) {
+        if len == 0 {
+            return;
+        }
+
+        let surname = self
+            .words
+            .drain(..len)
+            .collect::<SmallVec<[NamePart<'a>; 5]>>();
+        for (i, word) in surname.into_iter().enumerate() {
+            let location = if i == len - 1 {
+                Location::End
             } else {
-                self.words.len()
+                Location::Middle
             };
-        self.strip_postfix(first_postfix_index);
+            self.words.push(NamePart::from_word_and_counts(
+                word.word,
+                word.counts,
+                self.use_capitalization,
+                location,
+            ));
+        }
+        self.surname_index = self.words.len() - len;
+    }
+
+    // In mixed-case input, a single run of all-caps words is probably the
+    // surname if it starts or ends the name (e.g. "DUPONT Jean Pierre" or
+    // "Jean Pierre DUPONT"), as long as it includes something that looks more
+    // like a name than initials (so not e.g. "JM Smith" or "AL Doe"). In the
+    // middle of other names, it's more likely a middle name for emphasis
+    // ("Mary ELIZABETH Smith") or middle initials ("Poppy PELD Donahue").
+    fn find_capitalized_surname(&self) -> Option<Range<usize>> {
+        if !self.use_capitalization || self.words.len() < 2 {
+            return None;
+        }
+
+        let is_capitalized = |w: &NamePart| {
+            w.counts.alpha > 1 && w.counts.upper == w.counts.alpha && !w.word.contains('.')
+        };
+
+        let start = self.words.iter().position(is_capitalized)?;
+        let end = start
+            + self.words[start..]
+                .iter()
+                .take_while(|w| is_capitalized(w))
+                .count();
+
+        if end - start == self.words.len() || self.words[end..].iter().any(is_capitalized) {
+            return None;
+        }
+
+        if start > 0
+            && end < self.words.len()
+            && self.words[..start]
+                .iter()
+                .chain(&self.words[end..])
+                .any(|w| w.is_namelike())
+        {
+            return None;
+        }
 
-        self.surname_index = surname::find_surname_index(&self.words[1..]) + 1;
+        if self.is_capitalized_surname(start..end) {
+            Some(start..end)
+        } else {
+            None
+        }
+    }
+
+    // Two-letter words are only plausible surnames if we know them as such
+    // (e.g. "LI" or "NG", but not "AL")
+    fn is_capitalized_surname(&self, range: Range<usize>) -> bool {
+        self.words[range].iter().any(|w| {
+            (w.counts.alpha > 2 || romanization::canonical_surname(w.word).is_some())
+                && !starts_with_double_plosive(w.word)
+                && suffix::generation_from_suffix(w, true).is_none()
+                && NamePart::from_word_and_counts(w.word, w.counts.clone(), false, Location::End)
+                    .is_namelike()
+        })
+    }
+
+    // Whether an all-caps word could be part of a surname, as a name or a
+    // particle (e.g. "LE" in "Jean-Marie LE PEN" or "DU" in "Jean DU PONT")
+    fn might_be_in_surname(&self, word: &NamePart) -> bool {
+        NamePart::from_word_and_counts(word.word, word.counts.clone(), false, Location::End)
+            .is_namelike()
+            || surname::is_surname_prefix(&word.word.to_lowercase(), self.locale)
+    }
+
+    fn move_capitalized_surname_to_end(&mut self, range: Range<usize>) {
+        let len = range.len();
+        let surname = self
+            .words
+            .drain(range)
+            .collect::<SmallVec<[NamePart<'a>; 5]>>();
+        for (i, word) in surname.into_iter().enumerate() {
+            let location = if i == len - 1 {
+                Location::End
+            } else {
+                Location::Middle
+            };
+            if word.is_initials() {
+                self.flags |= ParseFlags::IGNORED_CAPITALIZATION;
+            }
+            self.words.push(NamePart::surname_from_word_and_counts(
+                word.word,
+                word.counts,
+                location,
+            ));
+        }
+        self.surname_index = self.words.len() - len;
     }
 
     // Called only until any words are found
@@ -259,19 +932,19 @@ impl<'a> ParseOp<'a> {
 
         // Strip non-comma-separated titles & suffixes (e.g. "John Smith Jr., MD")
         let first_postfix_index = title::find_postfix_index(&self.words[1..], false) + 1;
-        self.strip_postfix(first_postfix_index);
+        self.strip_postfix(first_postfix_index, false);
 
         if prefix_title_len > 0 {
             // Finding a prefix title means the next word is a first name or
             // initial (we don't support "Dr. Smith, John")
-            self.surname_index = surname::find_surname_index(&self.words[1..]) + 1;
+            self.surname_index = surname::find_surname_index(&self.words[1..], self.locale) + 1;
         } else {
             // Have to guess whether this is just the surname (as in "Smith, John")
             // or the full name (as in "John Smith")
             //
             // Note we might be wrong, and have to go back, if we think the given
             // name is coming after a comma, but it never does
-            self.surname_index = surname::find_surname_index(&self.words);
+            self.surname_index = surname::find_surname_index(&self.words, self.locale);
         }
     }
 
@@ -312,6 +985,7 @@ impl<'a> ParseOp<'a> {
 
             self.words.reserve(given_middle_or_postfix_words.len());
             self.words.insert_many(0, given_middle_or_postfix_words);
+            self.move_last_given_name_first();
         }
     }
 
@@ -358,14 +1032,14 @@ impl<'a> ParseOp<'a> {
             .position(|p| p.is_namelike() || p.is_initials())
     }
 
-    fn strip_postfix(&mut self, index: usize) {
+    fn strip_postfix(&mut self, index: usize, expect_initials: bool) {
         if index < self.words.len() {
             let postfixes = self
                 .words
                 .drain(index..)
                 .collect::<SmallVec<[NamePart<'a>; 5]>>();
             for postfix in postfixes {
-                self.found_suffix_or_postfix(postfix, false);
+                self.found_suffix_or_postfix(postfix, expect_initials);
             }
             self.words.truncate(index);
         }
@@ -383,6 +1057,7 @@ impl<'a> ParseOp<'a> {
         if self.generation_from_suffix.is_none() {
             if let Some(gen) = suffix::generation_from_suffix(&postfix, expect_initials) {
                 self.generation_from_suffix = Some(gen);
+                self.generation_word = Some(postfix.word);
                 return;
             }
         }
@@ -391,6 +1066,55 @@ impl<'a> ParseOp<'a> {
     }
 }
 
+// Whether the text is nothing but single initials with periods (e.g. "G." or
+// "J. R."), which can't be postfix titles
+fn title_as_name(title: NamePart) -> NamePart {
+    if title.is_namelike() {
+        return title;
+    }
+
+    let word = title.word.trim_end_matches('.');
+    NamePart {
+        word,
+        counts: categorize_chars(word),
+        category: Category::Name(Cow::Borrowed(word)),
+    }
+}
+
+fn only_initials(text: &str) -> bool {
+    let mut words = text.split_whitespace().peekable();
+    words.peek().is_some()
+        && words.all(|word| {
+            let mut chars = word.chars();
+            matches!(
+                (chars.next(), chars.next(), chars.next()),
+                (Some(c), Some('.'), None) if c.is_alphabetic()
+            )
+        })
+}
+
+// Names rarely start with a doubled plosive, unlike other doubled consonants
+// (as in "Lloyd", "Ffrench", "Nnaji", or "Ssempala"), but runs of initials
+// sometimes do ("PPELD")
+fn starts_with_double_plosive(word: &str) -> bool {
+    let mut chars = word.chars();
+    match (chars.next(), chars.next()) {
+        (Some(a), Some(b)) => {
+            let a = a.to_ascii_lowercase();
+            a == b.to_ascii_lowercase() && "bcdgkpqt".contains(a)
+        }
+        _ => false,
+    }
+}
+
+// Postfix titles and suffixes we can recognize without relying on
+// capitalization (e.g. "MD", "PHD" or "JR")
+fn is_known_postfix(word: &NamePart) -> bool {
+    title::is_known_initialism(word)
+        || suffix::generation_from_suffix(word, true).is_some()
+        || title::find_postfix_index(std::slice::from_ref(word), true) == 0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -426,6 +1150,19 @@ mod tests {
         assert_eq!(None, generation);
     }
 
+    #[test]
+    fn multi_word_last_initial() {
+        let Name {
+            parts,
+            surname_index,
+            ..
+        } = parse("Garcia Marquez, G.").unwrap();
+        assert_eq!("G.", parts[0].word);
+        assert_eq!("Garcia", parts[1].word);
+        assert_eq!("Marquez", parts[2].word);
+        assert_eq!(1, surname_index);
+    }
+
     #[test]
     fn last_first() {
         let Name {
@@ -542,33 +1279,310 @@ mod tests {
         assert_eq!("et al.", name.honorific_suffix().unwrap());
     }
 
+    #[test]
+    fn errors() {
+        assert_eq!(Some(ParseError::NoWords), parse(" ... 23 ").err());
+        assert_eq!(
+            Some(ParseError::OnlyTitles {
+                titles: vec!["Mr.".to_string(), "Dr.".to_string()]
+            }),
+            parse("Mr. Dr.").err()
+        );
+        assert_eq!(
+            Some(ParseError::SingleWord {
+                word: "Doe".to_string()
+            }),
+            parse("Dr. Doe").err()
+        );
+        assert_eq!(
+            Some(ParseError::OnlyTitles {
+                titles: vec!["Dr.".to_string(), "PhD".to_string()]
+            }),
+            parse("Dr., PhD").err()
+        );
+        assert_eq!(
+            Some(ParseError::SingleWord {
+                word: "John".to_string()
+            }),
+            parse("John 503(a)").err()
+        );
+        assert_eq!(
+            Some(ParseError::Implausible {
+                words: vec!["Esq.".to_string(), "Doe".to_string()]
+            }),
+            parse("Doe, Esq.").err()
+        );
+    }
+
+    #[test]
+    fn surname_first() {
+        let options = ParseOptions::new().name_order(NameOrder::SurnameFirst);
+        let surname_first = |input| parse_with(input, &options, Reading::default()).unwrap();
+
+        let Name {
+            parts,
+            surname_index,
+            generation,
+            ..
+        } = surname_first("DOE JOHN A JR");
+        assert_eq!("JOHN", parts[0].word);
+        assert_eq!("A", parts[1].word);
+        assert_eq!("DOE", parts[2].word);
+        assert_eq!(2, surname_index);
+        assert_eq!(NonZeroU8::new(2), generation);
+
+        let name = surname_first("Dr. van der Berg Jan");
+        assert_eq!("Jan", name.words()[0].word);
+        assert_eq!(1, name.surname_index);
+        assert_eq!(Some(Cow::Borrowed("Dr.")), name.honorific_prefix());
+
+        let name = surname_first("Doe, John");
+        assert_eq!("John", name.words()[0].word);
+        assert_eq!(1, name.surname_index);
+    }
+
+    #[test]
+    fn capitalized_surname() {
+        let surname = |input| {
+            let name = parse(input).unwrap();
+            name.words()[name.surname_index..]
+                .iter()
+                .map(|w| w.word)
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        assert_eq!("DUPONT", surname("DUPONT Jean Pierre"));
+        assert_eq!("Pierre", surname("Jean DUPONT Pierre"));
+        assert_eq!("Smith", surname("Mary ELIZABETH Smith"));
+        assert_eq!("LE PEN", surname("LE PEN Jean-Marie"));
+        assert_eq!("LE PEN", surname("Jean-Marie LE PEN"));
+        assert_eq!("DUPONT", surname("MR DUPONT Jean"));
+        assert_eq!("DUPONT", surname("Jean DUPONT MD"));
+        assert_eq!("Doe", surname("AL Doe"));
+        assert_eq!("Smith", surname("JM Smith"));
+        assert_eq!("Donahue", surname("PPELD Donahue"));
+        assert_eq!("Donahue", surname("Poppy PELD Donahue"));
+        assert_eq!("ROSSI", surname("Anna Maria ROSSI"));
+        assert_eq!("ROSSI", surname("ROSSI Anna Maria"));
+        assert_eq!("SMITH", surname("Jean Pierre SMITH"));
+        assert_eq!("LI", surname("LI Wei"));
+        assert_eq!("LI", surname("Wei LI"));
+        assert_eq!("NG", surname("NG Kevin"));
+        assert_eq!("NG", surname("Kevin NG"));
+        assert_eq!("NNAJI", surname("NNAJI Chukwuemeka"));
+        assert_eq!("NNAJI", surname("Chukwuemeka NNAJI"));
+        assert_eq!("Smith", surname("John Smith GBE KCB"));
+        assert_eq!("DU PONT", surname("Jean DU PONT"));
+    }
+
+    #[test]
+    fn patronymic() {
+        let patronymic = |input| {
+            let name = parse(input).unwrap();
+            name.patronymic.clone().map(|range| {
+                name.words()[range]
+                    .iter()
+                    .map(|w| w.word)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+        };
+
+        assert_eq!(
+            Some("Petrovich".to_string()),
+            patronymic("Ivan Petrovich Sidorov")
+        );
+        assert_eq!(
+            Some("Petrovich".to_string()),
+            patronymic("Sidorov Ivan Petrovich")
+        );
+        assert_eq!(
+            Some("Ivanovna".to_string()),
+            patronymic("Petrova, Olga Ivanovna")
+        );
+        assert_eq!(
+            Some("bin Salman".to_string()),
+            patronymic("Mohammed bin Salman")
+        );
+        assert_eq!(
+            Some("Einarsson".to_string()),
+            patronymic("Jon Einarsson Thorlacius")
+        );
+        assert_eq!(
+            Some("Gudmundsdottir".to_string()),
+            patronymic("Bjork Gudmundsdottir")
+        );
+        assert_eq!(None, patronymic("Anna Johansson"));
+        assert_eq!(None, patronymic("Mary Ben Davis"));
+        assert_eq!(None, patronymic("Roman Abramovich"));
+
+        // A known given name first means the surname is probably last
+        let name = parse("Peter Paul Petrovich").unwrap();
+        assert_eq!("Petrovich", name.words()[name.surname_index].word);
+    }
+
+    #[test]
+    fn family_name_first() {
+        let words = |input, locale| {
+            let options = ParseOptions::new().locale(locale);
+            let name = parse_with(input, &options, Reading::default()).unwrap();
+            let words = name.words().iter().map(|w| w.word).collect::<Vec<_>>();
+            (words.join(" "), name.surname_index)
+        };
+
+        assert_eq!(
+            ("János Péter Kovács".to_string(), 2),
+            words("Kovács János Péter", Locale::Hu)
+        );
+        assert_eq!(
+            ("János Kovács".to_string(), 1),
+            words("János Kovács", Locale::Hu)
+        );
+        assert_eq!(("Nagy Toth".to_string(), 1), words("Nagy Toth", Locale::Hu));
+        assert_eq!(
+            ("Kovács János".to_string(), 1),
+            words("Kovács János", Locale::Any)
+        );
+
+        // The last given name comes first
+        assert_eq!(
+            ("Thieu Van Nguyen".to_string(), 2),
+            words("Nguyen Van Thieu", Locale::Vi)
+        );
+        assert_eq!(
+            ("Thieu Van Nguyen".to_string(), 2),
+            words("Nguyen, Van Thieu", Locale::Vi)
+        );
+        assert_eq!(
+            ("Thieu Van Nguyen".to_string(), 2),
+            words("Thieu Van Nguyen", Locale::Vi)
+        );
+        assert_eq!(
+            ("Dao Hung Tran".to_string(), 2),
+            words("Tran Hung Dao", Locale::Vi)
+        );
+        assert_eq!(
+            ("Lam Nguyen".to_string(), 1),
+            words("Lam Nguyen", Locale::Vi)
+        );
+    }
+
+    #[test]
+    fn double_surname() {
+        let surnames = |input, locale| {
+            let options = ParseOptions::new().locale(locale);
+            let name = parse_with(input, &options, Reading::default()).unwrap();
+            let words = name.words();
+            (
+                words[name.surname_index..].len(),
+                name.second_surname_index.map(|i| words[i].word),
+            )
+        };
+
+        assert_eq!(
+            (2, Some("Márquez")),
+            surnames("Gabriel García Márquez", Locale::Es)
+        );
+        assert_eq!((1, None), surnames("Gabriel García Márquez", Locale::Any));
+        assert_eq!((1, None), surnames("Juan Carlos Pérez", Locale::Es));
+        assert_eq!((1, None), surnames("José María Pérez", Locale::Es));
+        assert_eq!(
+            (3, Some("Garcia")),
+            surnames("Juan Velasquez y Garcia", Locale::Any)
+        );
+        assert_eq!(
+            (3, Some("Santos")),
+            surnames("Maria da Silva Santos", Locale::Pt)
+        );
+        assert_eq!((2, None), surnames("Ludwig van Beethoven", Locale::Any));
+    }
+
+    #[test]
+    fn vancouver() {
+        let options = ParseOptions::new().initials_after_surname(true);
+        let words = |input| {
+            let name = parse_with(input, &options, Reading::default()).unwrap();
+            let words = name.words().iter().map(|w| w.word).collect::<Vec<_>>();
+            (words.join(" "), name.surname_index)
+        };
+
+        assert_eq!(("JA Smith".to_string(), 1), words("Smith JA"));
+        assert_eq!(("J. A. Smith".to_string(), 2), words("Smith J.A."));
+        assert_eq!(("J A Smith".to_string(), 2), words("Smith J A"));
+        assert_eq!(("HJ van der Berg".to_string(), 1), words("van der Berg HJ"));
+        assert_eq!(("J Doe".to_string(), 1), words("Doe J Jr"));
+        assert_eq!(("JA Doe".to_string(), 1), words("Doe JA III"));
+
+        // Without case cues, or where the initials look like something else
+        assert_eq!(("SMITH JA".to_string(), 1), words("SMITH JA"));
+        assert_eq!(("John NG".to_string(), 1), words("John NG"));
+        assert_eq!(("Jane Smith".to_string(), 1), words("Jane Smith MD"));
+        assert!(parse_with("Smith II", &options, Reading::default()).is_err());
+
+        // A single initial, with or without a period
+        assert_eq!(("J. Doe".to_string(), 1), words("Doe J."));
+        assert_eq!(("K Smith".to_string(), 1), words("Smith K"));
+
+        // Only with the option
+        let name = parse("John AB").unwrap();
+        assert_eq!("John", name.words()[0].word);
+        assert_eq!(1, name.surname_index);
+        assert!(parse("Doe J.").is_err());
+    }
+
+    #[test]
+    fn flags() {
+        assert_eq!(ParseFlags::empty(), parse("John Doe").unwrap().flags);
+        assert_eq!(ParseFlags::empty(), parse("J.M. Doe").unwrap().flags);
+        assert_eq!(ParseFlags::empty(), parse("Dr. John Doe").unwrap().flags);
+        assert_eq!(ParseFlags::FALSE_POSTFIX, parse("John Esq").unwrap().flags);
+        assert!(parse("Dr Doe")
+            .unwrap()
+            .flags
+            .contains(ParseFlags::FALSE_PREFIX));
+        assert_eq!(
+            ParseFlags::DISCARDED_POSTFIX,
+            parse("John Doe Q").unwrap().flags
+        );
+        assert_eq!(
+            ParseFlags::IGNORED_CAPITALIZATION,
+            parse("John NG").unwrap().flags
+        );
+        assert_eq!(
+            ParseFlags::REVISED_SURNAME,
+            parse("Van Buren, 1").unwrap().flags
+        );
+        assert_eq!(ParseFlags::GUESSED_INITIALS, parse("JM Doe").unwrap().flags);
+    }
+
     #[cfg(feature = "bench")]
     #[bench]
     fn parse_simple(b: &mut Bencher) {
-        b.iter(|| black_box(parse("John Doe").is_some()))
+        b.iter(|| black_box(parse("John Doe").is_ok()))
     }
 
     #[cfg(feature = "bench")]
     #[bench]
     fn parse_nonascii(b: &mut Bencher) {
-        b.iter(|| black_box(parse("이용희").is_some()))
+        b.iter(|| black_box(parse("이용희").is_ok()))
     }
 
     #[cfg(feature = "bench")]
     #[bench]
     fn parse_comma(b: &mut Bencher) {
-        b.iter(|| black_box(parse("Doe, John").is_some()))
+        b.iter(|| black_box(parse("Doe, John").is_ok()))
     }
 
     #[cfg(feature = "bench")]
     #[bench]
     fn parse_all_caps(b: &mut Bencher) {
-        b.iter(|| black_box(parse("JOHN DOE").is_some()))
+        b.iter(|| black_box(parse("JOHN DOE").is_ok()))
     }
 
     #[cfg(feature = "bench")]
     #[bench]
     fn parse_complex(b: &mut Bencher) {
-        b.iter(|| black_box(parse("James S. Brown MD, FRCS, FDSRCS").is_some()))
+        b.iter(|| black_box(parse("James S. Brown MD, FRCS, FDSRCS").is_ok()))
     }
 }
@@ -0,0 +1,352 @@
+use super::decomposition::normalize_nfkd_whitespace;
+use super::nickname::{self, have_matching_variants};
+use super::suffix;
+use super::transliterate;
+use super::{parse, Name, ParseFlags, ParseOptions};
+use std::borrow::Cow;
+use std::num::NonZeroU8;
+
+/// A human name which may be missing components that `Name` requires, such
+/// as "Dr. Smith" (no given name or initial) or "John D." (no surname, only
+/// its initial).
+///
+/// Construct a `PartialName` using `parse_partial`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PartialName {
+    given_name: Option<Box<str>>,
+    first_initial: Option<char>,
+    surname: Option<Box<str>>,
+    surname_initial: Option<char>,
+    generation: Option<NonZeroU8>,
+    honorific_prefix: Option<Box<str>>,
+    honorific_suffix: Option<Box<str>>,
+}
+
+/// Parses a string representing a single person's name, possibly lacking a
+/// given name or surname, into a `PartialName`.
+///
+/// Accepts anything `Name::parse` does (keeping the given name, surname,
+/// generational suffix, and honorifics, but not middle names), as well as an
+/// honorific prefix with a surname ("Mrs. Jones"), and a given name with a
+/// surname initial ("John D."). A single word without a title or initial is
+/// ambiguous, so it is rejected (but see `PersonName`).
+///
+/// # Examples
+/// ```
+/// use human_name::{parse_partial, Name};
+///
+/// let partial = parse_partial("Mrs. Jones").unwrap();
+/// assert_eq!(Some("Jones"), partial.surname());
+/// assert_eq!(None, partial.given_name());
+/// assert_eq!(Some("Mrs."), partial.honorific_prefix());
+///
+/// let partial = parse_partial("John D.").unwrap();
+/// assert_eq!(Some("John"), partial.given_name());
+/// assert_eq!(None, partial.surname());
+/// assert_eq!(Some('D'), partial.surname_initial());
+///
+/// let partial = parse_partial("Jane Doe").unwrap();
+/// assert_eq!(Some("Jane"), partial.given_name());
+/// assert_eq!(Some("Doe"), partial.surname());
+///
+/// assert!(parse_partial("Jones").is_none());
+/// ```
+pub fn parse_partial(name: &str) -> Option<PartialName> {
+    match Name::try_parse(name) {
+        Ok(full) if !full.parse_flags().contains(ParseFlags::FALSE_PREFIX) => {
+            return Some(PartialName::from_name(&full));
+        }
+        Ok(full) => {
+            // E.g. "Dr Smith", which parses only by taking "Dr" for initials
+            return PartialName::parse_single_word(name)
+                .or_else(|| Some(PartialName::from_name(&full)));
+        }
+        Err(_) => {}
+    }
+
+    PartialName::parse_single_word(name)
+}
+
+impl PartialName {
+    fn from_name(name: &Name) -> PartialName {
+        PartialName {
+            given_name: name.given_name().map(|n| n.into()),
+            first_initial: Some(name.first_initial()),
+            surname: Some(name.surname().into()),
+            surname_initial: name.surname().chars().next(),
+            generation: name.generation,
+            honorific_prefix: name.honorific_prefix().map(|p| p.into()),
+            honorific_suffix: name.honorific_suffix().map(|s| s.into()),
+        }
+    }
+
+    fn parse_single_word(name: &str) -> Option<PartialName> {
+        if name.len() >= super::MAX_NAME_LEN {
+            return None;
+        }
+
+        let name = normalize_nfkd_whitespace(name);
+        let stripped = nickname::strip_nickname(&name);
+        let (stripped, surname_initial) = split_surname_initial(&stripped);
+
+        let parsed = parse::parse_single_word(stripped, &ParseOptions::default())?;
+        let honorific_prefix = parsed.honorific_prefix();
+
+        let mut namecased = String::new();
+        parsed.words()[0].with_namecased(|s| namecased.push_str(s));
+        let word = namecased.into_boxed_str();
+        let initial = word.chars().next();
+
+        let (given_name, surname) = if surname_initial.is_some() {
+            (Some(word), None)
+        } else if honorific_prefix.is_some() {
+            (None, Some(word))
+        } else {
+            return None;
+        };
+
+        Some(PartialName {
+            first_initial: given_name.as_ref().and(initial),
+            surname_initial: surname_initial.or(initial),
+            given_name,
+            surname,
+            generation: parsed.generation,
+            honorific_prefix: honorific_prefix.map(|p| p.into()),
+            honorific_suffix: parsed.honorific_suffix().map(|s| s.into()),
+        })
+    }
+
+    /// Given name, if known
+    pub fn given_name(&self) -> Option<&str> {
+        self.given_name.as_deref()
+    }
+
+    /// First initial, if known
+    pub fn first_initial(&self) -> Option<char> {
+        self.first_initial
+    }
+
+    /// Surname, if known
+    pub fn surname(&self) -> Option<&str> {
+        self.surname.as_deref()
+    }
+
+    /// Initial of the surname, if known (even where the full surname is not)
+    pub fn surname_initial(&self) -> Option<char> {
+        self.surname_initial
+    }
+
+    /// Generational suffix, if present
+    pub fn generational_suffix(&self) -> Option<&str> {
+        self.generation.map(suffix::display_generational_suffix)
+    }
+
+    /// Honorific prefix(es), if present
+    pub fn honorific_prefix(&self) -> Option<&str> {
+        self.honorific_prefix.as_deref()
+    }
+
+    /// Honorific suffix(es), if present
+    pub fn honorific_suffix(&self) -> Option<&str> {
+        self.honorific_suffix.as_deref()
+    }
+
+    /// The known parts of the name, with initials where that is all we know,
+    /// and the generational suffix, but not honorifics.
+    ///
+    /// ```
+    /// use human_name::parse_partial;
+    ///
+    /// assert_eq!("John D.", parse_partial("JOHN D.").unwrap().display_full());
+    /// assert_eq!("Smith, Jr.", parse_partial("Mr. Smith Jr.").unwrap().display_full());
+    /// ```
+    pub fn display_full(&self) -> Cow<'_, str> {
+        let mut words: Vec<Cow<str>> = Vec::with_capacity(2);
+        match (&self.given_name, self.first_initial) {
+            (Some(given_name), _) => words.push(Cow::Borrowed(given_name)),
+            (None, Some(initial)) => words.push(Cow::Owned(format!("{}.", initial))),
+            _ => {}
+        }
+        match (&self.surname, self.surname_initial) {
+            (Some(surname), _) => words.push(Cow::Borrowed(surname)),
+            (None, Some(initial)) => words.push(Cow::Owned(format!("{}.", initial))),
+            _ => {}
+        }
+
+        let mut result = words.join(" ");
+        if let Some(suffix) = self.generational_suffix() {
+            result.push_str(", ");
+            result.push_str(suffix);
+        }
+        Cow::Owned(result)
+    }
+
+    /// The known parts of the name, including honorifics.
+    ///
+    /// ```
+    /// use human_name::parse_partial;
+    ///
+    /// let partial = parse_partial("Dr. Smith, MD").unwrap();
+    /// assert_eq!("Dr. Smith MD", partial.display_full_with_honorifics());
+    /// ```
+    pub fn display_full_with_honorifics(&self) -> Cow<'_, str> {
+        if self.honorific_prefix.is_none() && self.honorific_suffix.is_none() {
+            return self.display_full();
+        }
+
+        let full = self.display_full();
+        let words: Vec<&str> = self
+            .honorific_prefix()
+            .into_iter()
+            .chain(Some(&*full))
+            .chain(self.honorific_suffix())
+            .collect();
+        Cow::Owned(words.join(" "))
+    }
+
+    /// Might this partial name refer to the same person as a full name?
+    ///
+    /// Every component that is known must be consistent with the full name:
+    /// surnames are compared as by `Name::consistent_with`, given names allow
+    /// for nicknames (including one captured from the full name's input), and
+    /// initials must match the corresponding names. Middle names and
+    /// honorifics are ignored.
+    ///
+    /// ```
+    /// use human_name::{parse_partial, Name};
+    ///
+    /// let record = Name::parse("Mary Ann Jones").unwrap();
+    /// assert!(parse_partial("Mrs. Jones").unwrap().compatible_with(&record));
+    /// assert!(parse_partial("Molly J.").unwrap().compatible_with(&record));
+    /// assert!(!parse_partial("Mrs. Smith").unwrap().compatible_with(&record));
+    /// assert!(!parse_partial("Mary S.").unwrap().compatible_with(&record));
+    /// ```
+    pub fn compatible_with(&self, name: &Name) -> bool {
+        self.given_name_compatible(name)
+            && self.surname_compatible(name)
+            && self.suffix_compatible(name)
+    }
+
+    fn given_name_compatible(&self, name: &Name) -> bool {
+        if let (Some(mine), Some(theirs)) = (self.given_name(), name.given_name()) {
+            return have_matching_variants(mine, theirs)
+                || name
+                    .nickname()
+                    .map(|nickname| have_matching_variants(mine, nickname))
+                    .unwrap_or(false);
+        }
+
+        match self.first_initial {
+            Some(initial) => same_initial(initial, name.first_initial()),
+            None => true,
+        }
+    }
+
+    fn surname_compatible(&self, name: &Name) -> bool {
+        match (self.surname(), self.surname_initial) {
+            (Some(surname), _) => Name::surname_strs_consistent(surname, name.surname()),
+            (None, Some(initial)) => name
+                .surname()
+                .split(|c: char| c.is_whitespace() || c == '-')
+                .filter_map(|word| word.chars().next())
+                .any(|c| same_initial(initial, c)),
+            (None, None) => true,
+        }
+    }
+
+    fn suffix_compatible(&self, name: &Name) -> bool {
+        match (self.generation, name.generation) {
+            (Some(mine), Some(theirs)) => mine == theirs,
+            _ => true,
+        }
+    }
+}
+
+// Splits off a trailing surname initial, as in "John D." or "JOHN D", if
+// there's anything left before it
+fn split_surname_initial(name: &str) -> (&str, Option<char>) {
+    let trimmed = name.trim_end();
+    let (rest, last) = match trimmed.rfind(' ') {
+        Some(i) => (&trimmed[..i], &trimmed[i + 1..]),
+        None => return (name, None),
+    };
+
+    let mut chars = last.trim_end_matches('.').chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_alphabetic() => (rest, c.to_uppercase().next()),
+        _ => (name, None),
+    }
+}
+
+fn same_initial(a: char, b: char) -> bool {
+    let fold = |c: char| transliterate::to_ascii_initial(c).unwrap_or(c);
+    fold(a) == fold(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn title_and_surname() {
+        let partial = parse_partial("Dr. Smith").unwrap();
+        assert_eq!(Some("Smith"), partial.surname());
+        assert_eq!(Some("Dr."), partial.honorific_prefix());
+        assert_eq!(None, partial.first_initial());
+
+        let partial = parse_partial("Dr Smith").unwrap();
+        assert_eq!(Some("Smith"), partial.surname());
+        assert_eq!(Some("Dr."), partial.honorific_prefix());
+
+        let partial = parse_partial("MRS JONES IV").unwrap();
+        assert_eq!(Some("Jones"), partial.surname());
+        assert_eq!(Some("IV"), partial.generational_suffix());
+    }
+
+    #[test]
+    fn given_and_surname_initial() {
+        let partial = parse_partial("John D.").unwrap();
+        assert_eq!(Some("John"), partial.given_name());
+        assert_eq!(Some('J'), partial.first_initial());
+        assert_eq!(Some('D'), partial.surname_initial());
+
+        let partial = parse_partial("Dr. John D.").unwrap();
+        assert_eq!(Some("John"), partial.given_name());
+        assert_eq!(Some('D'), partial.surname_initial());
+        assert_eq!(Some("Dr."), partial.honorific_prefix());
+    }
+
+    #[test]
+    fn full() {
+        let partial = parse_partial("Dr. J. Doe III").unwrap();
+        assert_eq!(None, partial.given_name());
+        assert_eq!(Some('J'), partial.first_initial());
+        assert_eq!(Some("Doe"), partial.surname());
+        assert_eq!(Some("III"), partial.generational_suffix());
+        assert_eq!("J. Doe, III", partial.display_full());
+    }
+
+    #[test]
+    fn unparseable() {
+        assert!(parse_partial("").is_none());
+        assert!(parse_partial("Dr.").is_none());
+        assert!(parse_partial("Smith").is_none());
+        assert!(parse_partial("Smith 123").is_none());
+    }
+
+    #[test]
+    fn compatibility() {
+        let record = Name::parse("William 'Chip' de la Cruz, Jr.").unwrap();
+        let compatible = |input| parse_partial(input).unwrap().compatible_with(&record);
+
+        assert!(compatible("Mr. Cruz"));
+        assert!(compatible("Bill C."));
+        assert!(compatible("Chip D."));
+        assert!(compatible("Mr. Cruz Jr."));
+        assert!(!compatible("Mr. Cruz Sr."));
+        assert!(!compatible("Mr. Diaz"));
+        assert!(!compatible("Robert C."));
+        assert!(!compatible("Bill S."));
+        assert!(!compatible("Wendy de la Cruz"));
+    }
+}
@@ -0,0 +1,112 @@
+use super::decomposition::is_combining;
+use super::namepart::NamePart;
+use super::Locale;
+
+// East Slavic patronymics (e.g. "Petrovich", "Ivanovna", "Ilyinichna")
+const SLAVIC_SUFFIXES: [&str; 8] = [
+    "ovich", "evich", "ovych", "evych", "ovna", "evna", "ivna", "ichna",
+];
+
+// Common East Slavic surnames (e.g. "Sidorov", "Ivanova", "Tchaikovsky"), to
+// tell surname-first order from a given name followed by a patronymic-like
+// surname (e.g. "Kayla Rose Markovich")
+const SLAVIC_SURNAME_SUFFIXES: [&str; 9] = [
+    "ov", "ova", "ev", "eva", "sky", "skaya", "skiy", "skii", "enko",
+];
+
+// Nordic patronymics and matronymics (e.g. "Einarsson", "Guðmundsdóttir").
+// Many Swedish surnames also end in "-sson" (e.g. "Johansson"), so that's
+// only a patronymic when another surname follows it.
+const NORDIC_SUFFIXES: [&str; 1] = ["dottir"];
+const AMBIGUOUS_NORDIC_SUFFIXES: [&str; 1] = ["sson"];
+
+// Particles introducing a parent's name (e.g. "bin Salman", "ben David")
+const PARTICLES: [&str; 5] = ["bin", "ibn", "ben", "bint", "binti"];
+
+// Outside Arabic names, these are also ordinary names (e.g. "Ben" in "Mary
+// Ben Davis"), so we only take them for particles when they're lowercase
+pub fn is_particle(word: &str, locale: Locale) -> bool {
+    if locale == Locale::Ar {
+        PARTICLES.iter().any(|p| p.eq_ignore_ascii_case(word))
+    } else {
+        PARTICLES.contains(&word)
+    }
+}
+
+pub fn has_slavic_suffix(word: &NamePart) -> bool {
+    word.is_namelike() && SLAVIC_SUFFIXES.iter().any(|s| ends_with(word.word, s))
+}
+
+pub fn has_slavic_surname_suffix(word: &NamePart) -> bool {
+    word.is_namelike()
+        && SLAVIC_SURNAME_SUFFIXES
+            .iter()
+            .any(|s| ends_with(word.word, s))
+}
+
+pub fn has_nordic_suffix(word: &NamePart, followed_by_surname: bool) -> bool {
+    word.is_namelike()
+        && (NORDIC_SUFFIXES.iter().any(|s| ends_with(word.word, s))
+            || (followed_by_surname
+                && AMBIGUOUS_NORDIC_SUFFIXES
+                    .iter()
+                    .any(|s| ends_with(word.word, s))))
+}
+
+// Case-insensitive, and ignoring any accents (e.g. matching "DÓTTIR")
+fn ends_with(word: &str, suffix: &str) -> bool {
+    let mut chars = word.chars().rev().filter(|&c| !is_combining(c));
+    let matches = suffix
+        .chars()
+        .rev()
+        .all(|s| chars.next().map(|c| c.to_ascii_lowercase()) == Some(s));
+
+    // Don't take the whole word for a suffix
+    matches && chars.next().is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::namepart::Location;
+    use super::*;
+
+    fn part(word: &str) -> NamePart<'_> {
+        NamePart::from_word(word, true, Location::Middle)
+    }
+
+    #[test]
+    fn slavic() {
+        assert!(has_slavic_suffix(&part("Petrovich")));
+        assert!(has_slavic_suffix(&part("IVANOVNA")));
+        assert!(!has_slavic_suffix(&part("Sidorov")));
+        assert!(!has_slavic_suffix(&part("Ovna")));
+    }
+
+    #[test]
+    fn slavic_surnames() {
+        assert!(has_slavic_surname_suffix(&part("Sidorov")));
+        assert!(has_slavic_surname_suffix(&part("IVANOVA")));
+        assert!(!has_slavic_surname_suffix(&part("Kayla")));
+        assert!(!has_slavic_surname_suffix(&part("Svetlana")));
+    }
+
+    #[test]
+    fn nordic() {
+        assert!(has_nordic_suffix(&part("Einarsson"), true));
+        assert!(!has_nordic_suffix(&part("Johansson"), false));
+        assert!(has_nordic_suffix(&part("Gudmundsdottir"), false));
+        assert!(has_nordic_suffix(
+            &part("Gu\u{f0}mundsdo\u{301}ttir"),
+            false
+        ));
+        assert!(!has_nordic_suffix(&part("Jackson"), true));
+    }
+
+    #[test]
+    fn particles() {
+        assert!(is_particle("bin", Locale::Any));
+        assert!(is_particle("Ibn", Locale::Ar));
+        assert!(!is_particle("Ben", Locale::Any));
+        assert!(!is_particle("Al", Locale::Ar));
+    }
+}
@@ -0,0 +1,367 @@
+use super::decomposition::normalize_nfkd_whitespace;
+use super::nickname::{self, have_matching_variants};
+use super::suffix;
+use super::{parse, ComparisonOptions, Name, ParseError, ParseOptions};
+use std::borrow::Cow;
+use std::num::NonZeroU8;
+
+/// A parsed human name which, unlike `Name`, may consist of a single word.
+///
+/// Many people really do have only one name (common in Indonesia and
+/// Myanmar, for example, as well as among celebrities), so where such input
+/// is expected, parse into a `PersonName` rather than a `Name`.
+///
+/// # Examples
+/// ```
+/// use human_name::{Name, PersonName};
+///
+/// match PersonName::parse("Dr. Sukarno").unwrap() {
+///     PersonName::Mononym(mononym) => {
+///         assert_eq!("Sukarno", mononym.name());
+///         assert_eq!(Some("Dr."), mononym.honorific_prefix());
+///     }
+///     PersonName::Full(_) => unreachable!(),
+/// }
+///
+/// let name = PersonName::parse("Jane Doe").unwrap();
+/// assert_eq!("Doe", name.as_name().unwrap().surname());
+///
+/// assert!(PersonName::parse("Dr. PhD").is_none());
+/// ```
+#[derive(Clone, Debug)]
+pub enum PersonName {
+    /// A name with (what we think is) a surname and a first initial
+    Full(Name),
+    /// A name consisting of a single word, plus any titles
+    Mononym(Mononym),
+}
+
+/// A single-word name, along with any honorifics and generational suffix.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Mononym {
+    name: Box<str>,
+    generation: Option<NonZeroU8>,
+    honorific_prefix: Option<Box<str>>,
+    honorific_suffix: Option<Box<str>>,
+}
+
+impl PersonName {
+    /// Parses a string representing a single person's name, which may be
+    /// either a full name (see `Name::parse`) or a single word.
+    pub fn parse(name: &str) -> Option<PersonName> {
+        PersonName::try_parse(name).ok()
+    }
+
+    /// Like `parse`, but on failure, explains at which stage parsing failed
+    /// (see `Name::try_parse`).
+    ///
+    /// ```
+    /// use human_name::{ParseError, PersonName};
+    ///
+    /// assert!(PersonName::try_parse("Cher").is_ok());
+    /// assert_eq!(Some(ParseError::NoWords), PersonName::try_parse("12345").err());
+    /// ```
+    pub fn try_parse(name: &str) -> Result<PersonName, ParseError> {
+        PersonName::try_parse_with(name, &ParseOptions::default())
+    }
+
+    /// Like `parse`, but allows overriding our default heuristics (see
+    /// `ParseOptions`).
+    pub fn parse_with(name: &str, options: &ParseOptions) -> Option<PersonName> {
+        PersonName::try_parse_with(name, options).ok()
+    }
+
+    /// Like `parse_with`, but on failure, explains at which stage parsing
+    /// failed (see `Name::try_parse`).
+    pub fn try_parse_with(name: &str, options: &ParseOptions) -> Result<PersonName, ParseError> {
+        match Name::try_parse_with(name, options) {
+            Ok(name) => Ok(PersonName::Full(name)),
+            Err(error @ ParseError::SingleWord { .. }) => Mononym::parse_with(name, options)
+                .map(PersonName::Mononym)
+                .ok_or(error),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// The full name, if this is not a mononym
+    pub fn as_name(&self) -> Option<&Name> {
+        match self {
+            PersonName::Full(name) => Some(name),
+            PersonName::Mononym(_) => None,
+        }
+    }
+
+    /// The mononym, if this is one
+    pub fn as_mononym(&self) -> Option<&Mononym> {
+        match self {
+            PersonName::Full(_) => None,
+            PersonName::Mononym(mononym) => Some(mononym),
+        }
+    }
+
+    /// Generational suffix, if present
+    pub fn generational_suffix(&self) -> Option<&str> {
+        match self {
+            PersonName::Full(name) => name.generational_suffix(),
+            PersonName::Mononym(mononym) => mononym.generational_suffix(),
+        }
+    }
+
+    /// Honorific prefix(es), if present
+    pub fn honorific_prefix(&self) -> Option<&str> {
+        match self {
+            PersonName::Full(name) => name.honorific_prefix(),
+            PersonName::Mononym(mononym) => mononym.honorific_prefix(),
+        }
+    }
+
+    /// Honorific suffix(es), if present
+    pub fn honorific_suffix(&self) -> Option<&str> {
+        match self {
+            PersonName::Full(name) => name.honorific_suffix(),
+            PersonName::Mononym(mononym) => mononym.honorific_suffix(),
+        }
+    }
+
+    /// The name, including generational suffix but not honorifics (see
+    /// `Name::display_full`).
+    ///
+    /// ```
+    /// use human_name::PersonName;
+    ///
+    /// assert_eq!("John Doe", PersonName::parse("JOHN DOE").unwrap().display_full());
+    /// assert_eq!("Cher", PersonName::parse("CHER").unwrap().display_full());
+    /// ```
+    pub fn display_full(&self) -> Cow<'_, str> {
+        match self {
+            PersonName::Full(name) => name.display_full(),
+            PersonName::Mononym(mononym) => mononym.display_full(),
+        }
+    }
+
+    /// The name, including generational suffix and honorifics.
+    pub fn display_full_with_honorifics(&self) -> Cow<'_, str> {
+        match self {
+            PersonName::Full(name) => name.display_full_with_honorifics(),
+            PersonName::Mononym(mononym) => mononym.display_full_with_honorifics(),
+        }
+    }
+
+    /// Might this name represent the same person as another name?
+    ///
+    /// Full names are compared as by `Name::consistent_with`, and mononyms
+    /// are consistent with each other if the names match (ignoring case and
+    /// accents) and generational suffixes, if both present, are the same. A
+    /// mononym is never consistent with a full name, unless that is enabled
+    /// with `ComparisonOptions::match_mononyms` (see `consistent_with_options`).
+    ///
+    /// ```
+    /// use human_name::PersonName;
+    ///
+    /// let sukarno = PersonName::parse("Sukarno").unwrap();
+    /// assert!(sukarno.consistent_with(&PersonName::parse("SUKARNO").unwrap()));
+    /// assert!(!sukarno.consistent_with(&PersonName::parse("Suharto").unwrap()));
+    /// assert!(!sukarno.consistent_with(&PersonName::parse("Ahmad Sukarno").unwrap()));
+    /// ```
+    pub fn consistent_with(&self, other: &PersonName) -> bool {
+        self.consistent_with_options(other, &ComparisonOptions::default())
+    }
+
+    /// Like `consistent_with`, but with options controlling the comparison.
+    ///
+    /// With `match_mononyms` enabled, a mononym is consistent with a full
+    /// name if it matches either the given name (allowing for nicknames, as
+    /// in `Name::consistent_with`) or the surname, and the generational
+    /// suffixes don't conflict.
+    ///
+    /// ```
+    /// use human_name::{ComparisonOptions, PersonName};
+    ///
+    /// let options = ComparisonOptions::new().match_mononyms(true);
+    ///
+    /// let madonna = PersonName::parse("Madonna").unwrap();
+    /// let full = PersonName::parse("Madonna Louise Ciccone").unwrap();
+    /// assert!(madonna.consistent_with_options(&full, &options));
+    /// assert!(full.consistent_with_options(&madonna, &options));
+    ///
+    /// let ciccone = PersonName::parse("Ciccone").unwrap();
+    /// assert!(ciccone.consistent_with_options(&full, &options));
+    ///
+    /// let elvis = PersonName::parse("Elvis").unwrap();
+    /// assert!(!elvis.consistent_with_options(&full, &options));
+    /// ```
+    pub fn consistent_with_options(&self, other: &PersonName, options: &ComparisonOptions) -> bool {
+        match (self, other) {
+            (PersonName::Full(mine), PersonName::Full(theirs)) => {
+                mine.consistent_with_options(theirs, options)
+            }
+            (PersonName::Mononym(mine), PersonName::Mononym(theirs)) => {
+                mine.consistent_with(theirs)
+            }
+            (PersonName::Mononym(mononym), PersonName::Full(name))
+            | (PersonName::Full(name), PersonName::Mononym(mononym)) => {
+                options.match_mononyms && mononym.consistent_with_name(name)
+            }
+        }
+    }
+}
+
+impl Mononym {
+    fn parse_with(name: &str, options: &ParseOptions) -> Option<Mononym> {
+        let name = normalize_nfkd_whitespace(name);
+        let stripped = nickname::strip_nickname(&name);
+        let parsed = parse::parse_single_word(&stripped, options)?;
+
+        let mut namecased = String::new();
+        parsed.words()[0].with_namecased(|s| namecased.push_str(s));
+
+        Some(Mononym {
+            name: namecased.into_boxed_str(),
+            generation: parsed.generation,
+            honorific_prefix: parsed.honorific_prefix().map(|p| p.into()),
+            honorific_suffix: parsed.honorific_suffix().map(|s| s.into()),
+        })
+    }
+
+    /// The name itself, without titles or suffixes
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Generational suffix, if present
+    ///
+    /// ```
+    /// use human_name::PersonName;
+    ///
+    /// let name = PersonName::parse("Sukarno IV").unwrap();
+    /// assert_eq!(Some("IV"), name.as_mononym().unwrap().generational_suffix());
+    /// ```
+    pub fn generational_suffix(&self) -> Option<&str> {
+        self.generation.map(suffix::display_generational_suffix)
+    }
+
+    /// Honorific prefix(es), if present
+    pub fn honorific_prefix(&self) -> Option<&str> {
+        self.honorific_prefix.as_deref()
+    }
+
+    /// Honorific suffix(es), if present
+    pub fn honorific_suffix(&self) -> Option<&str> {
+        self.honorific_suffix.as_deref()
+    }
+
+    /// The name, including generational suffix but not honorifics
+    pub fn display_full(&self) -> Cow<'_, str> {
+        match self.generational_suffix() {
+            Some(suffix) => Cow::Owned(format!("{}, {}", self.name, suffix)),
+            None => Cow::Borrowed(&self.name),
+        }
+    }
+
+    /// The name, including generational suffix and honorifics
+    pub fn display_full_with_honorifics(&self) -> Cow<'_, str> {
+        if self.honorific_prefix.is_none() && self.honorific_suffix.is_none() {
+            return self.display_full();
+        }
+
+        let full = self.display_full();
+        let words: Vec<&str> = self
+            .honorific_prefix()
+            .into_iter()
+            .chain(Some(&*full))
+            .chain(self.honorific_suffix())
+            .collect();
+        Cow::Owned(words.join(" "))
+    }
+
+    /// Might this name represent the same person as another mononym?
+    ///
+    /// Requires that the names match, ignoring case and accents, and that
+    /// generational suffixes, if present in both names, are the same.
+    pub fn consistent_with(&self, other: &Mononym) -> bool {
+        Name::surname_strs_consistent(&self.name, &other.name)
+            && self.suffix_consistent(other.generation)
+    }
+
+    fn consistent_with_name(&self, name: &Name) -> bool {
+        let given_name_consistent = name
+            .given_name()
+            .into_iter()
+            .chain(name.nickname())
+            .any(|given_name| have_matching_variants(&self.name, given_name));
+
+        (given_name_consistent || Name::surname_strs_consistent(&self.name, name.surname()))
+            && self.suffix_consistent(name.generation)
+    }
+
+    fn suffix_consistent(&self, generation: Option<NonZeroU8>) -> bool {
+        match (self.generation, generation) {
+            (Some(mine), Some(theirs)) => mine == theirs,
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mononym(input: &str) -> Mononym {
+        match PersonName::parse(input) {
+            Some(PersonName::Mononym(mononym)) => mononym,
+            other => panic!("Expected mononym for {}, got {:?}", input, other),
+        }
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("Cher", mononym("Cher").name());
+        assert_eq!("Cher", mononym("CHER").name());
+        assert_eq!("Sukarno", mononym("Ir. Sukarno").name());
+        assert_eq!("Suharto", mononym("SUHARTO PHD").name());
+        assert_eq!(Some("Ph.D."), mononym("Suharto PhD").honorific_suffix());
+        assert_eq!("Madonna", mononym("Madonna ('Madge')").name());
+
+        assert!(matches!(
+            PersonName::parse("Jane Doe"),
+            Some(PersonName::Full(_))
+        ));
+        assert!(PersonName::parse("").is_none());
+        assert!(PersonName::parse("Dr.").is_none());
+        assert!(PersonName::parse("123").is_none());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("Sukarno, IV", mononym("Dr. Sukarno IV").display_full());
+        assert_eq!(
+            "Dr. Sukarno, IV",
+            mononym("Dr. Sukarno IV").display_full_with_honorifics()
+        );
+        assert_eq!(
+            "Suharto Ph.D.",
+            mononym("Suharto PhD").display_full_with_honorifics()
+        );
+    }
+
+    #[test]
+    fn consistency() {
+        assert!(mononym("Suharto").consistent_with(&mononym("SUHARTO")));
+        assert!(!mononym("Suharto").consistent_with(&mononym("Sukarno")));
+        assert!(!mononym("Sukarno II").consistent_with(&mononym("Sukarno IV")));
+
+        let full = PersonName::parse("William Smith Jr.").unwrap();
+        let options = ComparisonOptions::new().match_mononyms(true);
+        let consistent = |input| {
+            PersonName::parse(input)
+                .unwrap()
+                .consistent_with_options(&full, &options)
+        };
+        assert!(consistent("Bill"));
+        assert!(consistent("Smith"));
+        assert!(consistent("Smith Jr."));
+        assert!(!consistent("Smith Sr."));
+        assert!(!consistent("Jones"));
+        assert!(!PersonName::parse("Bill").unwrap().consistent_with(&full));
+    }
+}
@@ -0,0 +1,39 @@
+//! Surnames which are spelled differently under different romanizations of
+//! the same Chinese or Korean name (e.g. "Zhang", "Chang", and "Cheung", or
+//! "Lee", "Yi", and "Rhee").
+
+use super::transliterate;
+
+// Each romanization of a surname, mapped to the first one listed in the
+// data for the same name, which stands in for all of them
+static ROMANIZED_SURNAMES: phf::Map<&'static str, &'static str> =
+    include!(concat!(env!("OUT_DIR"), "/romanized_surnames.rs"));
+
+// Longer than any romanization we know of
+const MAX_ROMANIZATION_LEN: usize = 8;
+
+/// The romanization standing in for all known romanizations of the same
+/// surname, if the word is one of them
+pub fn canonical_surname(word: &str) -> Option<&'static str> {
+    // Fold on the stack, since we do this while parsing and comparing
+    let mut buffer = [0; MAX_ROMANIZATION_LEN];
+    let folded = transliterate::to_ascii_casefolded_in(word, &mut buffer)?;
+    ROMANIZED_SURNAMES.get(folded).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variants() {
+        assert_eq!(Some("zhang"), canonical_surname("Zhang"));
+        assert_eq!(Some("zhang"), canonical_surname("CHEUNG"));
+        assert_eq!(Some("li"), canonical_surname("Rhee"));
+        assert_eq!(Some("park"), canonical_surname("Bak"));
+        assert_eq!(Some("choi"), canonical_surname("Choe"));
+        assert_eq!(Some("li"), canonical_surname("李"));
+        assert_eq!(None, canonical_surname("Smith"));
+        assert_eq!(None, canonical_surname("Smith-Rhee"));
+    }
+}
@@ -2,7 +2,7 @@ use crate::features::categorize_chars;
 use crate::features::CharacterCounts;
 use unicode_segmentation::UnicodeSegmentation;
 
-pub const MAX_LEN: usize = u8::max_value() as usize;
+pub const MAX_LEN: usize = u8::MAX as usize;
 
 #[derive(Debug, Clone)]
 pub struct Segment<'a> {
@@ -18,10 +18,12 @@ struct PrettyNameParts<'a> {
     honorific_prefix: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     honorific_suffix: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nickname: Option<&'a str>,
 }
 
 impl Name {
-    fn to_pretty_parts(&self) -> PrettyNameParts {
+    fn to_pretty_parts(&self) -> PrettyNameParts<'_> {
         PrettyNameParts {
             first_initial: self.first_initial(),
             surname: self.surname(),
@@ -31,6 +33,7 @@ impl Name {
             generational_suffix: self.generational_suffix(),
             honorific_prefix: self.honorific_prefix(),
             honorific_suffix: self.honorific_suffix(),
+            nickname: self.nickname(),
         }
     }
 }
@@ -46,6 +49,12 @@ impl Serialize for Name {
     ///   r#"{"first_initial":"J","surname":"MacDonald","given_name":"John","middle_initials":"AQ","middle_names":"Allen","generational_suffix":"Jr.","honorific_prefix":"Dr."}"#,
     ///   serde_json::to_string(&name).unwrap()
     /// );
+    ///
+    /// let name = Name::parse("Jane 'JJ' Doe").unwrap();
+    /// assert_eq!(
+    ///   r#"{"first_initial":"J","surname":"Doe","given_name":"Jane","nickname":"JJ"}"#,
+    ///   serde_json::to_string(&name).unwrap()
+    /// );
     /// ```
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -0,0 +1,336 @@
+use super::decomposition::input_ranges;
+use super::nickname::Bracketed;
+use super::parse;
+use super::{Parenthetical, ParentheticalKind};
+use std::ops::Range;
+
+/// The byte ranges in the original input (before any normalization) from
+/// which each component of a `Name` was parsed, as returned by
+/// `Name::parse_with_spans`.
+///
+/// The given name span covers the same word as `Name::given_name`, so there
+/// is none when only initials precede the surname; every other word before
+/// the surname, including initials, is a middle name span. A run of initials
+/// like "JM" is a single word. Spans of quoted or bracketed text cover only
+/// the text inside the quotes or brackets.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NameSpans {
+    given_name: Option<Range<usize>>,
+    middle_names: Vec<Range<usize>>,
+    surname: Vec<Range<usize>>,
+    generational_suffix: Option<Range<usize>>,
+    honorific_prefixes: Vec<Range<usize>>,
+    honorific_suffixes: Vec<Range<usize>>,
+    parentheticals: Vec<Range<usize>>,
+    nickname: Option<Range<usize>>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Role {
+    GivenName,
+    MiddleName,
+    Surname,
+    GenerationalSuffix,
+    HonorificPrefix,
+    HonorificSuffix,
+}
+
+impl NameSpans {
+    pub(crate) fn new(
+        input: &str,
+        normalized: &str,
+        stripped: &str,
+        parsed: &parse::Name,
+        bracketed: &[Bracketed],
+        parentheticals: &[Parenthetical],
+    ) -> NameSpans {
+        let locator = Locator::new(input, normalized);
+
+        let words = parsed.words();
+        let given_index = words[..parsed.surname_index]
+            .iter()
+            .position(|word| !word.is_initials());
+        let mut roles: Vec<(&str, Role)> = Vec::with_capacity(words.len() + 2);
+        for (i, word) in words.iter().enumerate() {
+            let role = if i >= parsed.surname_index {
+                Role::Surname
+            } else if Some(i) == given_index {
+                Role::GivenName
+            } else {
+                Role::MiddleName
+            };
+            roles.push((word.word, role));
+        }
+        roles.extend(
+            parsed
+                .honorific_prefix_words()
+                .map(|w| (w, Role::HonorificPrefix)),
+        );
+        roles.extend(
+            parsed
+                .honorific_suffix_words()
+                .map(|w| (w, Role::HonorificSuffix)),
+        );
+        roles.extend(
+            parsed
+                .generation_word
+                .map(|w| (w, Role::GenerationalSuffix)),
+        );
+
+        // Locate words in input order, so that any we have to search for
+        // (where `stripped` is a copy) are found in the right place
+        roles.sort_by_key(|(word, _)| {
+            (word.as_ptr() as usize).wrapping_sub(stripped.as_ptr() as usize)
+        });
+
+        let mut spans = NameSpans::default();
+        let mut search_from = 0;
+        for (word, role) in roles {
+            let span = match locator.locate(word, &mut search_from) {
+                Some(span) => span,
+                None => continue,
+            };
+            match role {
+                Role::GivenName => spans.given_name = Some(span),
+                Role::MiddleName => spans.middle_names.push(span),
+                Role::Surname => spans.surname.push(span),
+                Role::GenerationalSuffix => spans.generational_suffix = Some(span),
+                Role::HonorificPrefix => spans.honorific_prefixes.push(span),
+                Role::HonorificSuffix => spans.honorific_suffixes.push(span),
+            }
+        }
+
+        // Bracketed text is never copied, so we needn't search for it
+        spans.parentheticals = bracketed
+            .iter()
+            .filter_map(|b| locator.locate(b.text, &mut 0))
+            .collect();
+        spans.nickname = parentheticals
+            .iter()
+            .zip(spans.parentheticals.iter())
+            .find(|(p, _)| p.kind() == ParentheticalKind::Nickname)
+            .map(|(_, span)| span.clone());
+
+        spans
+    }
+
+    /// The given name, corresponding to `Name::given_name`, if present
+    pub fn given_name(&self) -> Option<Range<usize>> {
+        self.given_name.clone()
+    }
+
+    /// Each other word before the surname, whether a name or initials
+    pub fn middle_names(&self) -> &[Range<usize>] {
+        &self.middle_names
+    }
+
+    /// Each word of the surname, in input order
+    pub fn surname(&self) -> &[Range<usize>] {
+        &self.surname
+    }
+
+    /// The generational suffix (e.g. "Jr."), if present
+    pub fn generational_suffix(&self) -> Option<Range<usize>> {
+        self.generational_suffix.clone()
+    }
+
+    /// Each word of the honorific prefix(es), in input order
+    pub fn honorific_prefixes(&self) -> &[Range<usize>] {
+        &self.honorific_prefixes
+    }
+
+    /// Each word of the honorific suffix(es), in input order
+    pub fn honorific_suffixes(&self) -> &[Range<usize>] {
+        &self.honorific_suffixes
+    }
+
+    /// Each quoted or bracketed span, corresponding to `Name::parentheticals`
+    pub fn parentheticals(&self) -> &[Range<usize>] {
+        &self.parentheticals
+    }
+
+    /// The nickname, corresponding to `Name::nickname`, if present
+    pub fn nickname(&self) -> Option<Range<usize>> {
+        self.nickname.clone()
+    }
+}
+
+// Maps words, which borrow either from the normalized input or from a copy
+// of it with parts removed, back to the original input
+struct Locator<'a> {
+    normalized: &'a str,
+    input_ranges: Option<Vec<Range<usize>>>,
+}
+
+impl<'a> Locator<'a> {
+    fn new(input: &str, normalized: &'a str) -> Locator<'a> {
+        let input_ranges = if normalized.as_ptr() == input.as_ptr() {
+            None
+        } else {
+            Some(input_ranges(input))
+        };
+
+        Locator {
+            normalized,
+            input_ranges,
+        }
+    }
+
+    fn locate(&self, word: &str, search_from: &mut usize) -> Option<Range<usize>> {
+        let base = self.normalized.as_ptr() as usize;
+        let ptr = word.as_ptr() as usize;
+        let start = if ptr >= base && ptr + word.len() <= base + self.normalized.len() {
+            ptr - base
+        } else {
+            self.find(word, *search_from)?
+        };
+
+        let end = start + word.len();
+        *search_from = end;
+        Some(self.to_input(start..end))
+    }
+
+    // Finds the next occurrence of the word which isn't part of a longer word
+    fn find(&self, word: &str, search_from: usize) -> Option<usize> {
+        let text = self.normalized;
+        let is_boundary = |c: Option<char>| !c.map(char::is_alphanumeric).unwrap_or(false);
+        text.get(search_from..)?
+            .match_indices(word)
+            .map(|(i, _)| search_from + i)
+            .find(|&i| {
+                is_boundary(text[..i].chars().next_back())
+                    && is_boundary(text[i + word.len()..].chars().next())
+            })
+    }
+
+    fn to_input(&self, range: Range<usize>) -> Range<usize> {
+        match &self.input_ranges {
+            Some(ranges) if range.start < range.end => {
+                ranges[range.start].start..ranges[range.end - 1].end
+            }
+            Some(ranges) => {
+                let start = ranges.get(range.start).map(|r| r.start).unwrap_or(0);
+                start..start
+            }
+            None => range,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{Name, ParseOptions};
+
+    fn spans(input: &str) -> Vec<(&'static str, &str)> {
+        spans_with(input, &ParseOptions::default())
+    }
+
+    fn spans_with<'a>(input: &'a str, options: &ParseOptions) -> Vec<(&'static str, &'a str)> {
+        let (_, spans) = Name::parse_with_spans_with(input, options).unwrap();
+
+        let mut result = Vec::new();
+        let mut push = |label, span: &std::ops::Range<usize>| {
+            result.push((label, &input[span.clone()]));
+        };
+        spans
+            .honorific_prefixes()
+            .iter()
+            .for_each(|s| push("prefix", s));
+        spans.given_name().iter().for_each(|s| push("given", s));
+        spans.middle_names().iter().for_each(|s| push("middle", s));
+        spans.surname().iter().for_each(|s| push("surname", s));
+        spans
+            .generational_suffix()
+            .iter()
+            .for_each(|s| push("generation", s));
+        spans
+            .honorific_suffixes()
+            .iter()
+            .for_each(|s| push("suffix", s));
+        spans.nickname().iter().for_each(|s| push("nickname", s));
+        result
+    }
+
+    #[test]
+    fn simple() {
+        assert_eq!(
+            vec![("given", "Jane"), ("surname", "Doe")],
+            spans("Jane Doe")
+        );
+        assert_eq!(
+            vec![("given", "Jane"), ("middle", "M."), ("surname", "Doe")],
+            spans("Jane M. Doe")
+        );
+    }
+
+    #[test]
+    fn initials() {
+        assert_eq!(
+            vec![("middle", "J."), ("middle", "M."), ("surname", "Doe")],
+            spans("J.M. Doe")
+        );
+        assert_eq!(
+            vec![("given", "Michael"), ("middle", "J."), ("surname", "Doe")],
+            spans("J. Michael Doe")
+        );
+
+        let options = ParseOptions::new().initials_after_surname(true);
+        let (name, spans) = Name::parse_with_spans_with("Smith JA", &options).unwrap();
+        assert_eq!(None, name.given_name());
+        assert_eq!(None, spans.given_name());
+        assert_eq!(
+            vec![("middle", "JA"), ("surname", "Smith")],
+            spans_with("Smith JA", &options)
+        );
+    }
+
+    #[test]
+    fn sort_order() {
+        assert_eq!(
+            vec![
+                ("prefix", "Dr."),
+                ("given", "John"),
+                ("middle", "Q"),
+                ("surname", "de"),
+                ("surname", "la"),
+                ("surname", "Cruz"),
+                ("generation", "Jr."),
+                ("suffix", "MD"),
+            ],
+            spans("de la Cruz, Dr. John Q, Jr., MD")
+        );
+    }
+
+    #[test]
+    fn nickname() {
+        assert_eq!(
+            vec![
+                ("given", "William"),
+                ("surname", "Smith"),
+                ("nickname", "Chip"),
+            ],
+            spans("William (Chip) Smith")
+        );
+        assert_eq!(
+            vec![("given", "Anna"), ("surname", "Müller")],
+            spans("Anna Müller geb. Schmidt")
+        );
+    }
+
+    #[test]
+    fn non_normalized() {
+        // Decomposes to "Jose\u{301}", among other things
+        assert_eq!(
+            vec![
+                ("given", "José"),
+                ("surname", "ﬁgueroa"),
+                ("nickname", "Pepe"),
+            ],
+            spans("José\u{a0}«Pepe» ﬁgueroa")
+        );
+        assert_eq!(
+            vec![("given", "Zoë"), ("surname", "Ångström")],
+            spans("Zoë\tÅngström")
+        );
+    }
+}
@@ -1,4 +1,8 @@
 use super::namepart::{Category, NamePart};
+use super::options::Locale;
+use super::patronymic;
+use super::transliterate;
+use std::cmp;
 
 const VOWELLESS_SURNAMES: [&str; 4] = ["Ng", "Lv", "Mtz", "Hdz"];
 
@@ -7,6 +11,121 @@ const SINGLE_LETTER_CONJUNCTIONS: [&str; 4] = ["e", "y", "E", "Y"];
 static SURNAME_PREFIXES: phf::Set<&'static str> =
     include!(concat!(env!("OUT_DIR"), "/surname_prefixes.rs"));
 
+/// Which part of a double surname (e.g. "García Márquez") is the paternal
+/// surname
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SurnameOrder {
+    /// Probably paternal first, as in Spanish, but we don't rely on it when
+    /// comparing names
+    Unknown,
+    /// Paternal first, as in Spanish (e.g. "García Márquez")
+    PaternalFirst,
+    /// Maternal first, as in Portuguese (e.g. "Silva Santos")
+    MaternalFirst,
+}
+
+impl SurnameOrder {
+    pub fn for_locale(locale: Locale) -> SurnameOrder {
+        match locale {
+            Locale::Es => SurnameOrder::PaternalFirst,
+            Locale::Pt => SurnameOrder::MaternalFirst,
+            _ => SurnameOrder::Unknown,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq)]
+enum Particle {
+    SurnamePrefix,
+    Conjunction,
+}
+
+static PARTICLES_EN: phf::Map<&'static str, Particle> =
+    include!(concat!(env!("OUT_DIR"), "/particles_en.rs"));
+static PARTICLES_ES: phf::Map<&'static str, Particle> =
+    include!(concat!(env!("OUT_DIR"), "/particles_es.rs"));
+static PARTICLES_PT: phf::Map<&'static str, Particle> =
+    include!(concat!(env!("OUT_DIR"), "/particles_pt.rs"));
+static PARTICLES_FR: phf::Map<&'static str, Particle> =
+    include!(concat!(env!("OUT_DIR"), "/particles_fr.rs"));
+static PARTICLES_IT: phf::Map<&'static str, Particle> =
+    include!(concat!(env!("OUT_DIR"), "/particles_it.rs"));
+static PARTICLES_NL: phf::Map<&'static str, Particle> =
+    include!(concat!(env!("OUT_DIR"), "/particles_nl.rs"));
+static PARTICLES_DE: phf::Map<&'static str, Particle> =
+    include!(concat!(env!("OUT_DIR"), "/particles_de.rs"));
+static PARTICLES_RU: phf::Map<&'static str, Particle> =
+    include!(concat!(env!("OUT_DIR"), "/particles_ru.rs"));
+static PARTICLES_HU: phf::Map<&'static str, Particle> =
+    include!(concat!(env!("OUT_DIR"), "/particles_hu.rs"));
+static PARTICLES_VI: phf::Map<&'static str, Particle> =
+    include!(concat!(env!("OUT_DIR"), "/particles_vi.rs"));
+static PARTICLES_AR: phf::Map<&'static str, Particle> =
+    include!(concat!(env!("OUT_DIR"), "/particles_ar.rs"));
+static PARTICLES_ZH: phf::Map<&'static str, Particle> =
+    include!(concat!(env!("OUT_DIR"), "/particles_zh.rs"));
+static PARTICLES_JA: phf::Map<&'static str, Particle> =
+    include!(concat!(env!("OUT_DIR"), "/particles_ja.rs"));
+static PARTICLES_KO: phf::Map<&'static str, Particle> =
+    include!(concat!(env!("OUT_DIR"), "/particles_ko.rs"));
+
+// Common family names, in lowercase ASCII, for locales where names are
+// written surname first
+static FAMILY_NAMES_HU: phf::Set<&'static str> =
+    include!(concat!(env!("OUT_DIR"), "/family_names_hu.rs"));
+static FAMILY_NAMES_VI: phf::Set<&'static str> =
+    include!(concat!(env!("OUT_DIR"), "/family_names_vi.rs"));
+
+fn particles(locale: Locale) -> Option<&'static phf::Map<&'static str, Particle>> {
+    match locale {
+        Locale::Any => None,
+        Locale::En => Some(&PARTICLES_EN),
+        Locale::Es => Some(&PARTICLES_ES),
+        Locale::Pt => Some(&PARTICLES_PT),
+        Locale::Fr => Some(&PARTICLES_FR),
+        Locale::It => Some(&PARTICLES_IT),
+        Locale::Nl => Some(&PARTICLES_NL),
+        Locale::De => Some(&PARTICLES_DE),
+        Locale::Ru => Some(&PARTICLES_RU),
+        Locale::Hu => Some(&PARTICLES_HU),
+        Locale::Vi => Some(&PARTICLES_VI),
+        Locale::Ar => Some(&PARTICLES_AR),
+        Locale::Zh => Some(&PARTICLES_ZH),
+        Locale::Ja => Some(&PARTICLES_JA),
+        Locale::Ko => Some(&PARTICLES_KO),
+    }
+}
+
+pub fn is_surname_prefix(word: &str, locale: Locale) -> bool {
+    match particles(locale) {
+        Some(particles) => particles.get(word) == Some(&Particle::SurnamePrefix),
+        None => SURNAME_PREFIXES.contains(word),
+    }
+}
+
+pub fn is_conjunction(word: &str, locale: Locale) -> bool {
+    match particles(locale) {
+        Some(particles) => particles.get(word) == Some(&Particle::Conjunction),
+        None => SINGLE_LETTER_CONJUNCTIONS.contains(&word),
+    }
+}
+
+/// Whether the word is a common family name in a locale where names are
+/// written surname first (e.g. "Kovács" in Hungarian or "Nguyễn" in
+/// Vietnamese), ignoring case and accents
+pub fn is_family_name(word: &NamePart, locale: Locale) -> bool {
+    let family_names = match locale {
+        Locale::Hu => &FAMILY_NAMES_HU,
+        Locale::Vi => &FAMILY_NAMES_VI,
+        _ => return false,
+    };
+
+    word.is_namelike()
+        && transliterate::to_ascii_casefolded(word.word)
+            .map(|chars| family_names.contains(chars.collect::<String>().as_str()))
+            .unwrap_or(false)
+}
+
 pub fn is_vowelless_surname(word: &str, use_capitalization: bool) -> bool {
     if use_capitalization {
         VOWELLESS_SURNAMES.contains(&word)
@@ -17,21 +136,28 @@ pub fn is_vowelless_surname(word: &str, use_capitalization: bool) -> bool {
     }
 }
 
-pub fn find_surname_index(words: &[NamePart]) -> usize {
+pub fn find_surname_index(words: &[NamePart], locale: Locale) -> usize {
     if words.len() < 2 {
         return 0;
     }
 
+    let mut patronymic_end = 0;
     for (i, word) in words[0..words.len() - 1].iter().enumerate() {
-        let key: &str = match word.category {
-            Category::Name(ref namecased) => namecased,
-            _ => word.word,
-        };
-        if SURNAME_PREFIXES.contains(key) {
+        if i < patronymic_end {
+            continue;
+        }
+
+        if is_surname_prefix(particle_key(word), locale) {
+            // A patronymic followed by more names (e.g. "bin Salman Al Saud")
+            // isn't the start of the surname
+            if patronymic::is_particle(word.word, locale) && i + 2 < words.len() {
+                patronymic_end = i + 2;
+                continue;
+            }
             return i;
         }
 
-        if i > 0 && SINGLE_LETTER_CONJUNCTIONS.contains(&word.word) {
+        if i > 0 && is_conjunction(word.word, locale) {
             // We found what looks like a conjunction in a Spanish or Portuguese
             // style surname (e.g. "Romero y Galdámez" or "Dato e Iradier"), so
             // the previous word was the start of the surname
@@ -45,6 +171,97 @@ pub fn find_surname_index(words: &[NamePart]) -> usize {
     words.len() - 1
 }
 
+/// For names in surname-first order (e.g. "Kim Il-sung"), find how many
+/// leading words make up the surname, always leaving at least one word for
+/// the given name
+pub fn find_surname_len(words: &[NamePart], locale: Locale) -> usize {
+    if words.len() < 2 {
+        return 0;
+    }
+
+    let max_len = words.len() - 1;
+
+    // Any particles (e.g. "VAN DER BERG JAN"), plus one more word
+    let mut len = words[..max_len]
+        .iter()
+        .take_while(|w| is_surname_prefix(particle_key(w), locale))
+        .count();
+    len = cmp::min(len + 1, max_len);
+
+    // Any conjunction, plus one more word (e.g. "Romero y Galdámez Óscar")
+    if len + 2 <= max_len
+        && is_conjunction(words[len].word, locale)
+        && !words[len - 1].is_initials()
+        && !words[len + 1].is_initials()
+    {
+        len += 2;
+    }
+
+    len
+}
+
+/// For a double surname in the Spanish or Portuguese style (e.g. "García
+/// Márquez", "Velasquez y Garcia" or "de la Vega Lopez"), find where the
+/// second surname starts. Each surname is one word, plus any particles. In
+/// other locales, we only split a surname with an explicit conjunction
+/// (e.g. "Velasquez y Garcia", but not "von und zu Liechtenstein").
+pub fn find_second_surname(words: &[NamePart], locale: Locale) -> Option<usize> {
+    let double_surnames_expected = matches!(locale, Locale::Es | Locale::Pt);
+    let conjunction_locale = if double_surnames_expected {
+        locale
+    } else {
+        Locale::Any
+    };
+
+    let mut second = None;
+    let mut conjoined = false;
+    let mut i = 0;
+    while i < words.len() {
+        let start =
+            if second.is_none() && i > 0 && is_conjunction(words[i].word, conjunction_locale) {
+                conjoined = true;
+                i + 1
+            } else {
+                i
+            };
+
+        i = start
+            + words[start..]
+                .iter()
+                .take_while(|w| is_surname_prefix(particle_key(w), locale))
+                .count();
+        match words.get(i) {
+            // A particle in some other locale is more likely part of a chain
+            // of particles than a surname in its own right (e.g. "zu")
+            Some(word)
+                if word.is_namelike() && !is_surname_prefix(particle_key(word), Locale::Any) => {}
+            _ => return None,
+        }
+        i += 1;
+
+        if start > 0 {
+            if second.is_some() {
+                // More than two surnames
+                return None;
+            }
+            second = Some(start);
+        }
+    }
+
+    if double_surnames_expected || conjoined {
+        second
+    } else {
+        None
+    }
+}
+
+fn particle_key<'a>(word: &'a NamePart) -> &'a str {
+    match word.category {
+        Category::Name(ref namecased) => namecased,
+        _ => word.word,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::namepart::{Location, NamePart};
@@ -53,67 +270,157 @@ mod tests {
     #[test]
     fn one_word() {
         let parts: Vec<_> = NamePart::all_from_text("Doe", true, Location::Start).collect();
-        assert_eq!(0, find_surname_index(&parts));
+        assert_eq!(0, find_surname_index(&parts, Locale::Any));
     }
 
     #[test]
     fn two_words() {
         let parts: Vec<_> = NamePart::all_from_text("Jane Doe", true, Location::Start).collect();
-        assert_eq!(1, find_surname_index(&parts));
+        assert_eq!(1, find_surname_index(&parts, Locale::Any));
     }
 
     #[test]
     fn three_words() {
         let parts: Vec<_> =
             NamePart::all_from_text("Jane Emily Doe", true, Location::Start).collect();
-        assert_eq!(2, find_surname_index(&parts));
+        assert_eq!(2, find_surname_index(&parts, Locale::Any));
     }
 
     #[test]
     fn conjunction_after_nothing() {
         let parts: Vec<_> = NamePart::all_from_text("y Velazquez", true, Location::Start).collect();
-        assert_eq!(1, find_surname_index(&parts));
+        assert_eq!(1, find_surname_index(&parts, Locale::Any));
     }
 
     #[test]
     fn conjunction_after_one() {
         let parts: Vec<_> =
             NamePart::all_from_text("Rodrigo y Velazquez", true, Location::Start).collect();
-        assert_eq!(0, find_surname_index(&parts));
+        assert_eq!(0, find_surname_index(&parts, Locale::Any));
     }
 
     #[test]
     fn conjunction_after_two() {
         let parts: Vec<_> =
             NamePart::all_from_text("Jane Rodrigo y Velazquez", true, Location::Start).collect();
-        assert_eq!(1, find_surname_index(&parts));
+        assert_eq!(1, find_surname_index(&parts, Locale::Any));
     }
 
     #[test]
     fn particle_after_nothing() {
         let parts: Vec<_> =
             NamePart::all_from_text("Abd al-Qader", true, Location::Start).collect();
-        assert_eq!(0, find_surname_index(&parts));
+        assert_eq!(0, find_surname_index(&parts, Locale::Any));
     }
 
     #[test]
     fn particle_after_one() {
         let parts: Vec<_> =
             NamePart::all_from_text("Jane Abd al-Qader", true, Location::Start).collect();
-        assert_eq!(1, find_surname_index(&parts));
+        assert_eq!(1, find_surname_index(&parts, Locale::Any));
     }
 
     #[test]
     fn particle_and_conjunction() {
         let parts: Vec<_> =
             NamePart::all_from_text("Alejandro de Aza y Cabra", true, Location::Start).collect();
-        assert_eq!(1, find_surname_index(&parts));
+        assert_eq!(1, find_surname_index(&parts, Locale::Any));
     }
 
     #[test]
     fn conjunction_and_particle() {
         let parts: Vec<_> =
             NamePart::all_from_text("Alejandro Cabra y de Aza", true, Location::Start).collect();
-        assert_eq!(1, find_surname_index(&parts));
+        assert_eq!(1, find_surname_index(&parts, Locale::Any));
+    }
+
+    #[test]
+    fn surname_first() {
+        let parts: Vec<_> = NamePart::all_from_text("Doe", true, Location::Start).collect();
+        assert_eq!(0, find_surname_len(&parts, Locale::Any));
+
+        let parts: Vec<_> = NamePart::all_from_text("Kim Il-sung", true, Location::Start).collect();
+        assert_eq!(1, find_surname_len(&parts, Locale::Any));
+
+        let parts: Vec<_> =
+            NamePart::all_from_text("van der Berg Jan", true, Location::Start).collect();
+        assert_eq!(3, find_surname_len(&parts, Locale::Any));
+
+        let parts: Vec<_> =
+            NamePart::all_from_text("van der Berg", true, Location::Start).collect();
+        assert_eq!(2, find_surname_len(&parts, Locale::Any));
+
+        let parts: Vec<_> =
+            NamePart::all_from_text("Romero y Galdámez Óscar", true, Location::Start).collect();
+        assert_eq!(3, find_surname_len(&parts, Locale::Any));
+
+        let parts: Vec<_> =
+            NamePart::all_from_text("Romero y Galdámez", true, Location::Start).collect();
+        assert_eq!(1, find_surname_len(&parts, Locale::Any));
+    }
+
+    #[test]
+    fn locale_particles() {
+        let parts: Vec<_> =
+            NamePart::all_from_text("Jan ter Horst", true, Location::Start).collect();
+        assert_eq!(1, find_surname_index(&parts, Locale::Nl));
+        assert_eq!(2, find_surname_index(&parts, Locale::It));
+
+        let parts: Vec<_> =
+            NamePart::all_from_text("Nguyen Van Thieu", true, Location::Start).collect();
+        assert_eq!(1, find_surname_index(&parts, Locale::Any));
+        assert_eq!(2, find_surname_index(&parts, Locale::Vi));
+
+        let parts: Vec<_> =
+            NamePart::all_from_text("JAN TEN BRINK", false, Location::Start).collect();
+        assert_eq!(1, find_surname_index(&parts, Locale::Nl));
+    }
+
+    #[test]
+    fn locale_conjunctions() {
+        let parts: Vec<_> =
+            NamePart::all_from_text("Jane Rodrigo y Velazquez", true, Location::Start).collect();
+        assert_eq!(1, find_surname_index(&parts, Locale::Es));
+        assert_eq!(3, find_surname_index(&parts, Locale::Pt));
+
+        let parts: Vec<_> =
+            NamePart::all_from_text("Jane Silva e Santos", true, Location::Start).collect();
+        assert_eq!(1, find_surname_index(&parts, Locale::Pt));
+        assert_eq!(3, find_surname_index(&parts, Locale::En));
+    }
+
+    #[test]
+    fn second_surname() {
+        let second = |text, locale| {
+            let parts: Vec<_> = NamePart::all_from_text(text, true, Location::Start).collect();
+            find_second_surname(&parts, locale)
+        };
+        assert_eq!(Some(1), second("García Márquez", Locale::Es));
+        assert_eq!(Some(2), second("Velasquez y Garcia", Locale::Any));
+        assert_eq!(Some(3), second("de la Vega Lopez", Locale::Es));
+        assert_eq!(None, second("de la Vega Lopez", Locale::Any));
+        assert_eq!(Some(2), second("da Silva Santos", Locale::Pt));
+        assert_eq!(None, second("Márquez", Locale::Es));
+        assert_eq!(None, second("van der Berg", Locale::Any));
+        assert_eq!(None, second("Ruiz Picasso y Lopez", Locale::Es));
+        assert_eq!(None, second("Müller Schmidt", Locale::De));
+        assert_eq!(None, second("von und zu Liechtenstein", Locale::Any));
+        assert_eq!(None, second("von und zu Liechtenstein", Locale::De));
+        assert_eq!(None, second("von und zu Liechtenstein", Locale::Es));
+    }
+
+    #[test]
+    fn family_names() {
+        let is_family_name = |word, locale| {
+            let part = NamePart::from_word(word, true, Location::Start);
+            super::is_family_name(&part, locale)
+        };
+        assert!(is_family_name("Kovács", Locale::Hu));
+        assert!(is_family_name("Nagy", Locale::Hu));
+        assert!(is_family_name("To\u{301}th", Locale::Hu));
+        assert!(is_family_name("Nguye\u{302}\u{303}n", Locale::Vi));
+        assert!(!is_family_name("János", Locale::Hu));
+        assert!(!is_family_name("Nguyen", Locale::Hu));
+        assert!(!is_family_name("Nguyen", Locale::Any));
     }
 }
@@ -7,6 +7,30 @@ use std::cmp;
 
 const TWO_CHAR_TITLES: [&str; 4] = ["mr", "ms", "sr", "dr"];
 
+// Postfix titles short enough to be confused with initials
+const COMMON_INITIALISMS: [&str; 23] = [
+    "md", "ma", "ba", "bs", "ms", "jd", "rn", "do", "np", "pa", "mba", "dds", "dmd", "dvm", "cpa",
+    "mph", "obe", "mbe", "cbe", "kbe", "dbe", "gbe", "kcb",
+];
+
+// Prefix titles which are rarely anything else, even without a period
+const UNAMBIGUOUS_TITLES: [&str; 14] = [
+    "mrs",
+    "miss",
+    "mx",
+    "prof",
+    "professor",
+    "sir",
+    "dame",
+    "rev",
+    "rabbi",
+    "imam",
+    "herr",
+    "frau",
+    "mme",
+    "mlle",
+];
+
 static HONORIFIC_PREFIXES: phf::Map<&'static str, &'static str> =
     include!(concat!(env!("OUT_DIR"), "/honorific_prefixes.rs"));
 
@@ -66,11 +90,60 @@ fn is_postfix_title(word: &NamePart, might_be_initials: bool) -> bool {
             HONORIFIC_SUFFIXES.contains_key(namecased.as_ref())
                 || namecased.chars().any(char::is_numeric)
         }
-        Category::Initials => !might_be_initials && word.counts.alpha > 1,
+        Category::Initials => {
+            (!might_be_initials && word.counts.alpha > 1) || is_unambiguous_initialism(word)
+        }
         _ => true,
     }
 }
 
+// Even when we expect initials, something like "PHD" is more likely a title;
+// but we can't say the same of two letters (e.g. "MD" or "JD")
+fn is_unambiguous_initialism(word: &NamePart) -> bool {
+    word.counts.alpha > 2
+        && word.counts.chars == word.counts.ascii_alpha
+        && HONORIFIC_SUFFIXES.contains_key(capitalize_word(word.word, true).as_str())
+}
+
+// Whether a short all-caps word is more likely a degree or similar (e.g. "MD"
+// or "PHD") than initials, when we'd otherwise guess initials
+pub fn is_known_initialism(word: &NamePart) -> bool {
+    is_unambiguous_initialism(word)
+        || COMMON_INITIALISMS
+            .iter()
+            .any(|title| title.eq_ignore_ascii_case(word.word))
+}
+
+// Whether the word is a known honorific prefix by itself (e.g. "Dr." or
+// "Mrs"), rather than just something that might be part of one. Since many
+// titles are also common words ("The", "Chief"), only abbreviations with a
+// period and a few unambiguous titles count.
+pub fn is_known_prefix(word: &str) -> bool {
+    let (word, period) = match word.strip_suffix('.') {
+        Some(word) => (word, true),
+        None => (word, false),
+    };
+    let matches = |t: &&str| t.eq_ignore_ascii_case(word);
+    TWO_CHAR_TITLES.iter().any(matches)
+        || UNAMBIGUOUS_TITLES.iter().any(matches)
+        || (period
+            && !word.is_empty()
+            && word.bytes().all(|b| b.is_ascii_alphabetic())
+            && HONORIFIC_PREFIXES.contains_key(capitalize_word(word, true).as_str()))
+}
+
+// Whether the word is a known honorific suffix (e.g. "PhD" or "Ph.D."),
+// rather than just something that might be one
+pub fn is_known_suffix(word: &str) -> bool {
+    let word = word.replace('.', "");
+    COMMON_INITIALISMS
+        .iter()
+        .any(|t| t.eq_ignore_ascii_case(&word))
+        || (word.len() > 2
+            && word.bytes().all(|b| b.is_ascii_alphabetic())
+            && HONORIFIC_SUFFIXES.contains_key(capitalize_word(&word, true).as_str()))
+}
+
 pub fn find_prefix_len(words: &[NamePart]) -> usize {
     let mut prefix_len = words.len() - 1;
 
@@ -476,6 +549,17 @@ mod tests {
         assert!(!is_postfix_title(&part, true));
     }
 
+    #[test]
+    fn is_postfix_title_known_initialism() {
+        let part = NamePart::from_word("PHD", true, Location::Start);
+        assert!(is_postfix_title(&part, false));
+        assert!(is_postfix_title(&part, true));
+
+        let part = NamePart::from_word("MD", true, Location::Start);
+        assert!(is_postfix_title(&part, false));
+        assert!(!is_postfix_title(&part, true));
+    }
+
     #[test]
     fn find_prefix_len_none() {
         let parts: Vec<_> = NamePart::all_from_text("Jane Doe", true, Location::Start).collect();
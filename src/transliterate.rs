@@ -60,6 +60,17 @@ pub fn to_ascii_casefolded(text: &str) -> Option<impl Iterator<Item = char> + '_
     }
 }
 
+/// Like `to_ascii_casefolded`, but written to the buffer (for looking up
+/// short words without allocating), or `None` if it doesn't fit
+pub fn to_ascii_casefolded_in<'a>(text: &str, buffer: &'a mut [u8]) -> Option<&'a str> {
+    let mut len = 0;
+    for c in to_ascii_casefolded(text)? {
+        *buffer.get_mut(len)? = c as u8;
+        len += 1;
+    }
+    std::str::from_utf8(&buffer[..len]).ok()
+}
+
 pub fn to_ascii_casefolded_reversed(text: &str) -> Option<impl Iterator<Item = char> + '_> {
     let mut result = text
         .chars()
@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use std::convert::TryInto;
 use std::ops::Range;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Location {
     start: u16,
     end: u16,
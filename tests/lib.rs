@@ -89,6 +89,94 @@ fn parsing() {
     }
 }
 
+#[test]
+fn candidates() {
+    let f = File::open("tests/parseable-names.txt").ok().unwrap();
+    let reader = BufReader::new(f);
+
+    for line in reader.lines() {
+        let line: String = line.ok().unwrap().nfkd().collect();
+
+        if line.starts_with('#') || !line.contains('|') {
+            continue;
+        }
+
+        let input = line.split('|').next().unwrap();
+        let name = human_name::Name::parse(input).unwrap();
+        let candidates = human_name::Name::parse_candidates(input);
+        assert!(
+            candidates
+                .iter()
+                .any(|(c, _)| c.display_full() == name.display_full()
+                    && c.surname() == name.surname()
+                    && c.given_name() == name.given_name()),
+            "[{}] Parse result missing from candidates",
+            input
+        );
+        assert!(
+            candidates
+                .windows(2)
+                .all(|pair| pair[0].1 >= pair[1].1 && pair[1].1 > 0.0),
+            "[{}] Candidates not sorted by score",
+            input
+        );
+    }
+}
+
+#[test]
+fn spans() {
+    let f = File::open("tests/parseable-names.txt").ok().unwrap();
+    let reader = BufReader::new(f);
+
+    for line in reader.lines() {
+        // Not normalized, so that spans must be mapped back to the original
+        let line = line.ok().unwrap();
+
+        if line.starts_with('#') || !line.contains('|') {
+            continue;
+        }
+
+        let input = line.split('|').next().unwrap();
+        let (name, spans) = human_name::Name::parse_with_spans(input).unwrap();
+        assert_eq!(
+            human_name::Name::parse(input).unwrap().display_full(),
+            name.display_full(),
+            "[{}] Parsed differently with spans",
+            input
+        );
+        assert_eq!(
+            name.surnames().len(),
+            spans.surname().len(),
+            "[{}] Wrong number of surname spans",
+            input
+        );
+        assert_eq!(
+            name.given_name().is_some(),
+            spans.given_name().is_some(),
+            "[{}] Given name span without a given name, or vice versa",
+            input
+        );
+
+        let all = spans
+            .given_name()
+            .into_iter()
+            .chain(spans.middle_names().iter().cloned())
+            .chain(spans.surname().iter().cloned())
+            .chain(spans.generational_suffix())
+            .chain(spans.honorific_prefixes().iter().cloned())
+            .chain(spans.honorific_suffixes().iter().cloned())
+            .chain(spans.parentheticals().iter().cloned());
+        for span in all {
+            assert!(
+                input.get(span.clone()).map(|s| !s.is_empty()) == Some(true),
+                "[{}] Invalid span {:?}",
+                input,
+                span
+            );
+        }
+    }
+}
+
 #[test]
 fn unparseable() {
     let f = File::open("tests/unparseable-names.txt").ok().unwrap();
@@ -166,3 +254,35 @@ fn equality() {
         }
     }
 }
+
+#[test]
+fn consistent_names_hash_alike() {
+    let f = File::open("tests/equal-names.txt").ok().unwrap();
+    let reader = BufReader::new(f);
+
+    // Across lines as well as within them, since blocking on the hash must
+    // never separate consistent names
+    let names: Vec<human_name::Name> = reader
+        .lines()
+        .map(|line| line.ok().unwrap())
+        .filter(|line| !line.starts_with('#'))
+        .flat_map(|line| {
+            let parts: Vec<String> = line.split('|').take(2).map(String::from).collect();
+            parts
+        })
+        .filter_map(|input| human_name::Name::parse(&input))
+        .collect();
+
+    for a in &names {
+        for b in &names {
+            if a.consistent_with(b) {
+                assert!(
+                    a.surname_hash() == b.surname_hash(),
+                    "{} is consistent with {} but has a different hash!",
+                    a.display_full(),
+                    b.display_full()
+                );
+            }
+        }
+    }
+}